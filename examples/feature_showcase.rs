@@ -1,51 +1,29 @@
-use async_trait::async_trait;
 use std::time::Duration;
-use timer_lib::{RecurringSchedule, Timer, TimerCallback, TimerError, TimerRegistry};
+use timer_lib::{RecurringSchedule, Timer, TimerError, TimerRegistry};
 use tokio::time::sleep;
 
-struct OneTimeCallback;
-struct RecurringCallback;
-struct ErrorCallback;
-
-#[async_trait]
-impl TimerCallback for OneTimeCallback {
-    async fn execute(&self) -> Result<(), TimerError> {
-        println!("One-time timer executed");
-        Ok(())
-    }
-}
-
-#[async_trait]
-impl TimerCallback for RecurringCallback {
-    async fn execute(&self) -> Result<(), TimerError> {
-        println!("Recurring timer executed");
-        Ok(())
-    }
-}
-
-#[async_trait]
-impl TimerCallback for ErrorCallback {
-    async fn execute(&self) -> Result<(), TimerError> {
-        Err(TimerError::callback_failed("Simulated error"))
-    }
-}
-
 #[tokio::main]
 async fn main() {
     let registry = TimerRegistry::new();
 
     let one_time_timer = Timer::once(Duration::from_secs(2))
-        .start(OneTimeCallback)
+        .start(|| async {
+            println!("One-time timer executed");
+            Ok(())
+        })
         .await
         .unwrap();
-    registry.insert(one_time_timer.clone()).await;
+    registry.insert(one_time_timer.clone()).await.unwrap();
 
     let recurring_timer =
         Timer::recurring(RecurringSchedule::new(Duration::from_secs(3)).with_expiration_count(5))
-            .start(RecurringCallback)
+            .start(|| async {
+                println!("Recurring timer executed");
+                Ok(())
+            })
             .await
             .unwrap();
-    let recurring_timer_id = registry.insert(recurring_timer.clone()).await;
+    let recurring_timer_id = registry.insert(recurring_timer.clone()).await.unwrap();
     let mut recurring_events = recurring_timer.subscribe();
     let mut recurring_completion = recurring_timer.completion();
 
@@ -80,7 +58,9 @@ async fn main() {
 
     let error_timer = Timer::new();
     error_timer
-        .start_once(Duration::from_secs(1), ErrorCallback)
+        .start_once(Duration::from_secs(1), || async {
+            Err(TimerError::callback_failed("Simulated error"))
+        })
         .await
         .unwrap();
     let error_outcome = error_timer.join().await.unwrap();
@@ -7,20 +7,33 @@ use std::sync::Arc;
 use std::time::Duration;
 
 /// Represents timer-related failures.
+///
+/// With the `serde` feature the error serializes as its kind; the captured
+/// backtrace is not serialized.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "TimerErrorKind", into = "TimerErrorKind")
+)]
 pub struct TimerError {
     kind: TimerErrorKind,
     backtrace: Arc<Backtrace>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum TimerErrorKind {
     InvalidParameter(String),
     NotRunning,
     NotPaused,
     ReentrantOperation(String),
-    CallbackTimedOut(Duration),
+    CallbackTimedOut(
+        #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))] Duration,
+    ),
     CallbackFailed(String),
+    CallbackPanicked(String),
+    CapacityExceeded(usize),
 }
 
 impl TimerError {
@@ -54,6 +67,16 @@ impl TimerError {
         Self::new(TimerErrorKind::CallbackFailed(message.into()))
     }
 
+    /// Creates an error for callbacks that panicked.
+    pub fn callback_panicked(message: impl Into<String>) -> Self {
+        Self::new(TimerErrorKind::CallbackPanicked(message.into()))
+    }
+
+    /// Creates an error for a registry that already holds `max_timers` active timers.
+    pub fn capacity_exceeded(max_timers: usize) -> Self {
+        Self::new(TimerErrorKind::CapacityExceeded(max_timers))
+    }
+
     /// Returns true when the error is an invalid parameter error.
     pub fn is_invalid_parameter(&self) -> bool {
         matches!(self.kind, TimerErrorKind::InvalidParameter(_))
@@ -116,6 +139,32 @@ impl TimerError {
         }
     }
 
+    /// Returns true when the error indicates the callback panicked.
+    pub fn is_callback_panicked(&self) -> bool {
+        matches!(self.kind, TimerErrorKind::CallbackPanicked(_))
+    }
+
+    /// Returns true when a bounded registry rejected a timer.
+    pub fn is_capacity_exceeded(&self) -> bool {
+        matches!(self.kind, TimerErrorKind::CapacityExceeded(_))
+    }
+
+    /// Returns the registry capacity that was exceeded, when available.
+    pub fn capacity_limit(&self) -> Option<usize> {
+        match self.kind {
+            TimerErrorKind::CapacityExceeded(max_timers) => Some(max_timers),
+            _ => None,
+        }
+    }
+
+    /// Returns the callback panic message when available.
+    pub fn callback_panic_message(&self) -> Option<&str> {
+        match &self.kind {
+            TimerErrorKind::CallbackPanicked(message) => Some(message.as_str()),
+            _ => None,
+        }
+    }
+
     /// Returns the captured backtrace.
     pub fn backtrace(&self) -> &Backtrace {
         self.backtrace.as_ref()
@@ -146,6 +195,15 @@ impl Display for TimerError {
             TimerErrorKind::CallbackFailed(message) => {
                 write!(f, "Callback execution failed: {message}")
             }
+            TimerErrorKind::CallbackPanicked(message) => {
+                write!(f, "Callback panicked: {message}")
+            }
+            TimerErrorKind::CapacityExceeded(max_timers) => {
+                write!(
+                    f,
+                    "Registry is full: at most {max_timers} active timers can be tracked."
+                )
+            }
         }
     }
 }
@@ -162,6 +220,20 @@ impl Eq for TimerError {}
 
 // Rust guideline compliant 2026-02-21
 
+#[cfg(feature = "serde")]
+impl From<TimerErrorKind> for TimerError {
+    fn from(kind: TimerErrorKind) -> Self {
+        Self::new(kind)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<TimerError> for TimerErrorKind {
+    fn from(error: TimerError) -> Self {
+        error.kind
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -192,6 +264,14 @@ mod tests {
             TimerError::callback_failed("boom").to_string(),
             "Callback execution failed: boom"
         );
+        assert_eq!(
+            TimerError::callback_panicked("boom").to_string(),
+            "Callback panicked: boom"
+        );
+        assert_eq!(
+            TimerError::capacity_exceeded(4).to_string(),
+            "Registry is full: at most 4 active timers can be tracked."
+        );
     }
 
     #[test]
@@ -205,6 +285,11 @@ mod tests {
         assert!(callback.is_callback_failed());
         assert_eq!(callback.callback_failure_message(), Some("boom"));
 
+        let panicked = TimerError::callback_panicked("boom");
+        assert!(panicked.is_callback_panicked());
+        assert_eq!(panicked.callback_panic_message(), Some("boom"));
+        assert!(!panicked.is_callback_failed());
+
         let timed_out = TimerError::callback_timed_out(Duration::from_millis(250));
         assert!(timed_out.is_callback_timed_out());
         assert_eq!(
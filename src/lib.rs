@@ -112,22 +112,37 @@
 //!
 //! Public operations return [`TimerError`] for invalid configuration or invalid
 //! lifecycle transitions.
+//!
+//! # Thread safety
+//!
+//! The crate contains no `unsafe` code. [`Timer`], [`TimerRegistry`] and the
+//! other handles are `Send + Sync` because every field they share is, so they
+//! can be cloned and moved across threads freely.
+
+#![forbid(unsafe_code)]
 
 pub mod errors;
 pub mod registry;
+#[cfg(feature = "serde")]
+mod serde_duration;
 pub mod timer;
 
 pub use errors::TimerError;
 pub use registry::TimerRegistry;
 #[deprecated(note = "Use TimerRegistry instead.")]
 pub type TimerManager = TimerRegistry;
-pub use registry::RegisteredTimer;
-#[cfg(feature = "test-util")]
-pub use timer::MockRuntime;
+pub use registry::{AggregateStats, CallbackRegistry, Fraction, RegisteredTimer};
+#[cfg(feature = "profiling")]
+pub use timer::LockProfile;
 pub use timer::{
-    RecurringCadence, RecurringSchedule, RetryBackoff, RetryPolicy, Timer, TimerBuilder,
-    TimerCallback, TimerCompletion, TimerEvent, TimerEvents, TimerFinishReason, TimerMetadata,
-    TimerOutcome, TimerSnapshot, TimerState, TimerStatistics,
+    CallbackGroup, CircuitBreaker, CircuitState, ContextCallback, CronSchedule, DriftHistogram,
+    ExecutionRecord, OneShotOutcome, OverlapPolicy, PanicPolicy, RecurringCadence,
+    RecurringSchedule, ResumeCatchUp, RetryBackoff, RetryPolicy, StatisticsCheckpoint,
+    StatisticsDelta, StatsLevel, TimeOfDay, Timer, TimerBuilder, TimerCallback, TimerCompletion,
+    TimerConfig, TimerContext, TimerEvent, TimerEvents, TimerFinishReason, TimerMetadata,
+    TimerOutcome, TimerSnapshot, TimerState, TimerStatistics, WeakTimer,
 };
+#[cfg(feature = "test-util")]
+pub use timer::{MockRuntime, TestScheduler};
 
 // Rust guideline compliant 2026-02-21
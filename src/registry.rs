@@ -1,17 +1,19 @@
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::{
-    atomic::{AtomicU64, Ordering},
+    atomic::{AtomicBool, AtomicU64, Ordering},
     Arc,
 };
 use std::time::Duration;
 
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, Notify, RwLock, Semaphore};
 use tokio::time::Instant;
 
 use crate::errors::TimerError;
 use crate::timer::driver::RuntimeHandle;
 use crate::timer::{
-    RecurringSchedule, Timer, TimerCallback, TimerMetadata, TimerOutcome, TimerSnapshot, TimerState,
+    OneShotOutcome, PanicPolicy, RecurringSchedule, SharedCallback, Timer, TimerCallback,
+    TimerConfig, TimerMetadata, TimerOutcome, TimerSnapshot, TimerState, WeakTimer,
 };
 
 /// Snapshot of a timer tracked by the registry.
@@ -31,23 +33,393 @@ pub struct RegisteredTimer {
     pub last_outcome: Option<TimerOutcome>,
     /// Metadata associated with the timer.
     pub metadata: TimerMetadata,
+    /// When the timer was created.
+    pub created_at: Instant,
+}
+
+/// Totals across every timer tracked by a registry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AggregateStats {
+    /// Number of tracked timers.
+    pub timers: usize,
+    /// Number of running timers.
+    pub running: usize,
+    /// Number of paused timers.
+    pub paused: usize,
+    /// Number of stopped timers.
+    pub stopped: usize,
+    /// Number of timers whose last run completed on its own.
+    pub completed: usize,
+    /// Executions summed across all timers.
+    pub total_executions: usize,
+    /// Failed executions summed across all timers.
+    pub total_errors: usize,
+}
+
+/// Named callbacks that schedule files refer to.
+#[derive(Clone, Default)]
+pub struct CallbackRegistry {
+    callbacks: HashMap<String, Arc<dyn TimerCallback>>,
+}
+
+impl CallbackRegistry {
+    /// Creates an empty callback registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `callback` under `name`, replacing any previous callback with that name.
+    pub fn register<F>(&mut self, name: impl Into<String>, callback: F)
+    where
+        F: TimerCallback + 'static,
+    {
+        self.callbacks.insert(name.into(), Arc::new(callback));
+    }
+
+    /// Returns true when a callback is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.callbacks.contains_key(name)
+    }
+
+    /// Returns the number of registered callbacks.
+    pub fn len(&self) -> usize {
+        self.callbacks.len()
+    }
+
+    /// Returns true when no callbacks are registered.
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+}
+
+/// Timer definitions loaded by [`TimerRegistry::load_from_toml`].
+#[cfg(feature = "toml")]
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScheduleFile {
+    #[serde(default)]
+    timer: Vec<TimerDefinition>,
+}
+
+#[cfg(feature = "toml")]
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TimerDefinition {
+    name: String,
+    interval_ms: u64,
+    #[serde(default = "default_recurring")]
+    recurring: bool,
+    expiration_count: Option<usize>,
+    callback: String,
+}
+
+#[cfg(feature = "toml")]
+fn default_recurring() -> bool {
+    true
 }
 
 /// A registry for tracking timers by identifier.
 #[derive(Clone, Default)]
 pub struct TimerRegistry {
-    timers: Arc<RwLock<HashMap<u64, Timer>>>,
-    next_id: Arc<AtomicU64>,
+    inner: Arc<RegistryInner>,
+}
+
+/// An interval expressed as a fraction of a registry's base period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fraction {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Fraction {
+    /// Creates a fraction; both parts must be greater than zero.
+    pub fn new(numerator: u32, denominator: u32) -> Result<Self, TimerError> {
+        if numerator == 0 || denominator == 0 {
+            return Err(TimerError::invalid_parameter(
+                "Fraction numerator and denominator must be greater than zero.",
+            ));
+        }
+
+        Ok(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Returns the numerator.
+    pub fn numerator(self) -> u32 {
+        self.numerator
+    }
+
+    /// Returns the denominator.
+    pub fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Scales `base` by the fraction, saturating on overflow.
+    pub fn of(self, base: Duration) -> Duration {
+        base.checked_mul(self.numerator)
+            .map_or(Duration::MAX, |scaled| scaled / self.denominator)
+    }
+}
+
+/// Random number generator shared by the timers of a seeded registry.
+pub(crate) type SharedRng = Arc<std::sync::Mutex<fastrand::Rng>>;
+
+/// Registry state shared with the timers it tracks.
+#[derive(Default)]
+pub(crate) struct RegistryInner {
+    timers: RwLock<HashMap<u64, Timer>>,
+    /// Next identifier; only advanced while holding the `timers` write lock.
+    next_id: AtomicU64,
     runtime: RuntimeHandle,
+    base_period: RwLock<Option<Duration>>,
+    derived: RwLock<HashMap<u64, Fraction>>,
+    pub(crate) panic_policy: RwLock<PanicPolicy>,
+    pub(crate) concurrency: Option<Arc<Semaphore>>,
+    rate_limit: Mutex<Option<RateLimiter>>,
+    named_schedules: RwLock<HashMap<u64, NamedSchedule>>,
+    reject_duplicate_schedules: AtomicBool,
+    pub(crate) jitter_rng: Option<SharedRng>,
+    max_timers: Option<usize>,
+    priority_ordering: AtomicBool,
+    priority_window: std::sync::RwLock<Option<Duration>>,
+    dispatch: PriorityDispatch,
+}
+
+/// Place of a due timer within its dispatch group: highest priority first,
+/// then lowest registry identifier.
+type GroupMember = (Reverse<u8>, u64);
+
+/// Serializes the callbacks of timers that fall due within one priority window.
+///
+/// Due timers are grouped by deadline: a group starts at the earliest
+/// deadline and takes every timer due before the window has passed, so
+/// timers scheduled microseconds apart still share a group.
+#[derive(Default)]
+struct PriorityDispatch {
+    groups: std::sync::Mutex<BTreeMap<Instant, BTreeSet<GroupMember>>>,
+    released: Notify,
+}
+
+impl PriorityDispatch {
+    fn groups(&self) -> std::sync::MutexGuard<'_, BTreeMap<Instant, BTreeSet<GroupMember>>> {
+        self.groups
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Adds `member` to the group covering `deadline` and returns its start.
+    fn join(&self, deadline: Instant, window: Duration, member: GroupMember) -> Instant {
+        let mut groups = self.groups();
+        let covers = |start: Instant, deadline: Instant| {
+            start
+                .checked_add(window)
+                .is_none_or(|window_end| deadline < window_end)
+        };
+        let start = groups
+            .range(..=deadline)
+            .next_back()
+            .map(|(start, _)| *start)
+            .filter(|start| covers(*start, deadline))
+            .or_else(|| {
+                groups
+                    .range(deadline..)
+                    .next()
+                    .map(|(start, _)| *start)
+                    .filter(|start| covers(deadline, *start))
+            })
+            .unwrap_or(deadline);
+        groups.entry(start).or_default().insert(member);
+        start
+    }
+
+    /// Returns whether a member of the group starting at `start` is ahead of `member`.
+    fn is_blocked(&self, start: Instant, member: GroupMember) -> bool {
+        self.groups()
+            .get(&start)
+            .and_then(BTreeSet::first)
+            .is_some_and(|first| *first != member)
+    }
+
+    fn leave(&self, start: Instant, member: GroupMember) {
+        let mut groups = self.groups();
+        if let Some(group) = groups.get_mut(&start) {
+            group.remove(&member);
+            if group.is_empty() {
+                groups.remove(&start);
+            }
+        }
+    }
+}
+
+/// A timer's turn in its dispatch group; releases the next timer when dropped.
+pub(crate) struct DispatchTurn {
+    registry: Arc<RegistryInner>,
+    start: Instant,
+    member: GroupMember,
+}
+
+impl Drop for DispatchTurn {
+    fn drop(&mut self) {
+        self.registry.dispatch.leave(self.start, self.member);
+        self.registry.dispatch.released.notify_waiters();
+    }
+}
+
+/// Schedule and callback name of a timer started from a [`CallbackRegistry`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NamedSchedule {
+    kind: NamedScheduleKind,
+    callback: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NamedScheduleKind {
+    // Only schedule files start named one-shot timers.
+    #[cfg_attr(not(feature = "toml"), allow(dead_code))]
+    Once(Duration),
+    Recurring(RecurringSchedule),
+}
+
+/// Token bucket holding a single token that refills at a fixed rate.
+///
+/// Each execution reserves the next free slot, so bursts are spread out
+/// evenly instead of being dropped.
+struct RateLimiter {
+    per_second: u32,
+    next_free: Option<Instant>,
+}
+
+impl RateLimiter {
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let slot = self.next_free.map_or(now, |next_free| next_free.max(now));
+        self.next_free = Some(slot + Duration::from_secs(1) / self.per_second);
+        slot - now
+    }
+}
+
+impl RegistryInner {
+    /// Waits for the turn of the timer `id` due at `deadline`.
+    ///
+    /// Returns `None` without waiting unless priority ordering is enabled.
+    /// Otherwise waits on `runtime` until the priority window of the
+    /// timer's group has passed, so every timer due in it has queued, and
+    /// then until every higher ordered member of the group has released its
+    /// turn. The turn must be held while the callback executes.
+    pub(crate) async fn enter_dispatch(
+        self: Arc<Self>,
+        runtime: &RuntimeHandle,
+        deadline: Instant,
+        priority: u8,
+        id: u64,
+    ) -> Option<DispatchTurn> {
+        if !self.priority_ordering.load(Ordering::SeqCst) {
+            return None;
+        }
+
+        let window = self.priority_window();
+        let member = (Reverse(priority), id);
+        let start = self.dispatch.join(deadline, window, member);
+        let turn = DispatchTurn {
+            registry: self,
+            start,
+            member,
+        };
+        if let Some(window_end) = start.checked_add(window) {
+            runtime.sleep_until(window_end).await;
+        }
+
+        loop {
+            let dispatch = &turn.registry.dispatch;
+            let released = dispatch.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+            if !dispatch.is_blocked(start, member) {
+                break;
+            }
+            released.await;
+        }
+        Some(turn)
+    }
+
+    fn priority_window(&self) -> Duration {
+        self.priority_window
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .unwrap_or(TimerRegistry::DEFAULT_PRIORITY_WINDOW)
+    }
+
+    /// Reserves an execution slot under the global rate limit.
+    ///
+    /// Returns how long the caller must wait before executing.
+    pub(crate) async fn reserve_execution(&self, now: Instant) -> Duration {
+        self.rate_limit
+            .lock()
+            .await
+            .as_mut()
+            .map_or(Duration::ZERO, |limiter| limiter.reserve(now))
+    }
 }
 
 impl TimerRegistry {
+    /// Priority window of a registry that never had one set.
+    pub const DEFAULT_PRIORITY_WINDOW: Duration = Duration::from_millis(10);
+
     /// Creates a new timer registry.
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry that limits how many tracked callbacks execute at once.
+    ///
+    /// Every callback attempt of a tracked timer waits for a permit before
+    /// executing. A limit of zero is treated as one.
+    pub fn with_max_concurrency(max_concurrency: usize) -> Self {
+        Self {
+            inner: Arc::new(RegistryInner {
+                concurrency: Some(Arc::new(Semaphore::new(max_concurrency.max(1)))),
+                ..RegistryInner::default()
+            }),
+        }
+    }
+
+    /// Creates a registry that holds at most `max_timers` active timers.
+    ///
+    /// Starting or adding a timer while `max_timers` tracked timers are
+    /// running or paused fails with [`TimerError::capacity_exceeded`] instead
+    /// of growing the registry, which guards services that create timers from
+    /// untrusted input. Stopped and completed timers stay tracked but free
+    /// their slot. Capacity is only checked when a timer is added, so
+    /// restarting a stopped timer through its own handle can exceed it.
+    pub fn with_capacity(max_timers: usize) -> Self {
+        Self {
+            inner: Arc::new(RegistryInner {
+                max_timers: Some(max_timers),
+                ..RegistryInner::default()
+            }),
+        }
+    }
+
+    /// Returns the maximum number of active timers, if the registry is bounded.
+    pub fn max_timers(&self) -> Option<usize> {
+        self.inner.max_timers
+    }
+
+    /// Creates a registry whose timers draw all jitter from one RNG seeded with `seed`.
+    ///
+    /// Timers created by or inserted into the registry share the generator,
+    /// so the same seed and the same sequence of operations reproduce the
+    /// same jitter across runs.
+    pub fn with_seed(seed: u64) -> Self {
         Self {
-            timers: Arc::new(RwLock::new(HashMap::new())),
-            next_id: Arc::new(AtomicU64::new(0)),
-            runtime: RuntimeHandle::default(),
+            inner: Arc::new(RegistryInner {
+                jitter_rng: Some(Arc::new(std::sync::Mutex::new(fastrand::Rng::with_seed(
+                    seed,
+                )))),
+                ..RegistryInner::default()
+            }),
         }
     }
 
@@ -55,23 +427,384 @@ impl TimerRegistry {
     #[cfg(feature = "test-util")]
     pub fn new_mocked() -> (Self, crate::timer::MockRuntime) {
         let runtime = crate::timer::MockRuntime::new();
-        (
-            Self {
-                timers: Arc::new(RwLock::new(HashMap::new())),
-                next_id: Arc::new(AtomicU64::new(0)),
-                runtime: runtime.handle(),
-            },
-            runtime,
-        )
+        (Self::with_runtime(runtime.handle()), runtime)
+    }
+
+    #[cfg(feature = "test-util")]
+    pub(crate) fn with_runtime(runtime: RuntimeHandle) -> Self {
+        Self {
+            inner: Arc::new(RegistryInner {
+                runtime,
+                ..RegistryInner::default()
+            }),
+        }
+    }
+
+    pub(crate) fn from_inner(inner: Arc<RegistryInner>) -> Self {
+        Self { inner }
+    }
+
+    /// Limits how many callbacks all tracked timers execute per second combined.
+    ///
+    /// Every callback attempt draws from a shared token bucket before
+    /// executing. Executions beyond the limit are delayed, never dropped, and
+    /// are spread out evenly over each second.
+    pub async fn set_global_rate_limit(&self, per_second: u32) -> Result<(), TimerError> {
+        if per_second == 0 {
+            return Err(TimerError::invalid_parameter(
+                "Global rate limit must be greater than zero.",
+            ));
+        }
+
+        *self.inner.rate_limit.lock().await = Some(RateLimiter {
+            per_second,
+            next_free: None,
+        });
+        Ok(())
+    }
+
+    /// Removes the global rate limit.
+    pub async fn clear_global_rate_limit(&self) {
+        *self.inner.rate_limit.lock().await = None;
+    }
+
+    /// Returns the global rate limit in executions per second, if one is set.
+    pub async fn global_rate_limit(&self) -> Option<u32> {
+        self.inner
+            .rate_limit
+            .lock()
+            .await
+            .as_ref()
+            .map(|limiter| limiter.per_second)
+    }
+
+    /// Sets whether starting a named timer that duplicates a registered one is an error.
+    ///
+    /// Two timers are duplicates when they use the same schedule and the same
+    /// callback name. Only timers started by name through
+    /// [`TimerRegistry::start_by_name`] or a schedule file are compared, and
+    /// only while they are running or paused, so a stopped or completed timer
+    /// frees its schedule even before it is pruned.
+    pub fn set_reject_duplicate_schedules(&self, reject_duplicate_schedules: bool) {
+        self.inner
+            .reject_duplicate_schedules
+            .store(reject_duplicate_schedules, Ordering::SeqCst);
+    }
+
+    /// Returns whether duplicate named schedules are rejected.
+    pub fn rejects_duplicate_schedules(&self) -> bool {
+        self.inner.reject_duplicate_schedules.load(Ordering::SeqCst)
+    }
+
+    /// Sets whether timers that fall due together run in priority order.
+    ///
+    /// When enabled, the callbacks of timers due within one priority window
+    /// (see [`TimerRegistry::set_priority_window`]) run one after another,
+    /// highest [`Timer::priority`] first; equal priorities run in insertion
+    /// order. Each timer holds its turn for its first callback attempt only,
+    /// so retries and their backoff never stall the rest of the group.
+    /// Timers due further apart are unaffected, and ticks fired early through
+    /// [`Timer::trigger_now`] or [`Timer::fire_now`] are not ordered.
+    /// Disabled by default, in which case due timers run concurrently.
+    pub fn set_priority_ordering(&self, priority_ordering: bool) {
+        self.inner
+            .priority_ordering
+            .store(priority_ordering, Ordering::SeqCst);
+    }
+
+    /// Sets how close together deadlines must be for their timers to be ordered.
+    ///
+    /// A group starts at the earliest deadline and takes every timer due
+    /// before `window` has passed. Its callbacks wait until the window is over
+    /// so that timers woken slightly late can still queue, which delays every
+    /// ordered tick by up to `window`. Timers that wake after their group
+    /// has already run join it late and are ordered only among the members
+    /// still waiting. Defaults to [`TimerRegistry::DEFAULT_PRIORITY_WINDOW`].
+    pub fn set_priority_window(&self, window: Duration) {
+        *self
+            .inner
+            .priority_window
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(window);
+    }
+
+    /// Returns the window within which due timers are ordered by priority.
+    pub fn priority_window(&self) -> Duration {
+        self.inner.priority_window()
+    }
+
+    /// Returns whether due timers run in priority order.
+    pub fn orders_by_priority(&self) -> bool {
+        self.inner.priority_ordering.load(Ordering::SeqCst)
+    }
+
+    /// Starts and registers a recurring timer running the callback registered as `callback`.
+    ///
+    /// Fails when no callback has that name, or when duplicate schedules are
+    /// rejected and a registered timer already runs the same callback on the
+    /// same schedule.
+    pub async fn start_by_name(
+        &self,
+        callback: &str,
+        schedule: RecurringSchedule,
+        callbacks: &CallbackRegistry,
+    ) -> Result<(u64, Timer), TimerError> {
+        let resolved = Self::resolve_callback(callbacks, callback)?;
+        let named = NamedSchedule {
+            kind: NamedScheduleKind::Recurring(schedule),
+            callback: callback.to_string(),
+        };
+        self.start_named(None, named, resolved).await
+    }
+
+    /// Counts the timers that hold a capacity slot: those running or paused.
+    fn active_count(timers: &HashMap<u64, Timer>) -> usize {
+        timers
+            .values()
+            .filter(|timer| timer.try_state().is_some_and(TimerState::is_active))
+            .count()
+    }
+
+    /// Fails early when `additional` more timers would not fit in a bounded registry.
+    async fn ensure_capacity(&self, additional: usize) -> Result<(), TimerError> {
+        match self.inner.max_timers {
+            Some(max_timers)
+                if Self::active_count(&*self.inner.timers.read().await)
+                    .saturating_add(additional)
+                    > max_timers =>
+            {
+                Err(TimerError::capacity_exceeded(max_timers))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Tracks a timer started by the registry, cancelling it if the registry is full.
+    async fn track_started(&self, timer: &Timer) -> Result<u64, TimerError> {
+        match self.insert(timer.clone()).await {
+            Ok(id) => Ok(id),
+            Err(err) => {
+                let _ = timer.cancel().await;
+                Err(err)
+            }
+        }
+    }
+
+    fn resolve_callback(
+        callbacks: &CallbackRegistry,
+        name: &str,
+    ) -> Result<Arc<dyn TimerCallback>, TimerError> {
+        callbacks
+            .callbacks
+            .get(name)
+            .cloned()
+            .ok_or_else(|| TimerError::invalid_parameter(format!("Unknown callback '{name}'.")))
+    }
+
+    fn duplicate_schedule_error(named: &NamedSchedule) -> TimerError {
+        TimerError::invalid_parameter(format!(
+            "A timer running callback '{}' on the same schedule is already registered.",
+            named.callback
+        ))
+    }
+
+    /// Returns the named schedules of the timers that are running or paused.
+    async fn active_named_schedules<'a>(
+        &self,
+        named_schedules: &'a HashMap<u64, NamedSchedule>,
+    ) -> Vec<&'a NamedSchedule> {
+        let timers = self.inner.timers.read().await;
+        named_schedules
+            .iter()
+            .filter(|(id, _)| {
+                timers
+                    .get(id)
+                    .is_some_and(|timer| timer.try_state().is_none_or(TimerState::is_active))
+            })
+            .map(|(_, named)| named)
+            .collect()
+    }
+
+    async fn start_named(
+        &self,
+        label: Option<String>,
+        named: NamedSchedule,
+        callback: Arc<dyn TimerCallback>,
+    ) -> Result<(u64, Timer), TimerError> {
+        let mut named_schedules = self.inner.named_schedules.write().await;
+        if self.rejects_duplicate_schedules()
+            && self
+                .active_named_schedules(&named_schedules)
+                .await
+                .contains(&&named)
+        {
+            return Err(Self::duplicate_schedule_error(&named));
+        }
+        self.ensure_capacity(1).await?;
+
+        let timer = self.new_timer().await;
+        if let Some(label) = label {
+            timer.set_label(label).await;
+        }
+        let callback = SharedCallback(callback);
+        match named.kind {
+            NamedScheduleKind::Once(delay) => timer.start_once(delay, callback).await?,
+            NamedScheduleKind::Recurring(schedule) => {
+                timer.start_recurring(schedule, callback).await?
+            }
+        };
+        let id = self.track_started(&timer).await?;
+        named_schedules.insert(id, named);
+        Ok((id, timer))
+    }
+
+    /// Returns the policy applied when a tracked timer's callback panics.
+    pub async fn panic_policy(&self) -> PanicPolicy {
+        *self.inner.panic_policy.read().await
+    }
+
+    /// Sets the policy applied when a tracked timer's callback panics.
+    ///
+    /// The policy takes effect for the next panic of every tracked timer.
+    pub async fn set_panic_policy(&self, policy: PanicPolicy) {
+        *self.inner.panic_policy.write().await = policy;
     }
 
     /// Inserts an existing timer and returns its identifier.
-    pub async fn insert(&self, timer: Timer) -> u64 {
-        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
-        self.timers.write().await.insert(id, timer);
+    ///
+    /// Identifiers are allocated under the same lock that stores the timer, so
+    /// concurrent inserts receive unique, increasing identifiers in the order
+    /// they complete. Fails with [`TimerError::capacity_exceeded`] when a
+    /// bounded registry already holds `max_timers` running or paused timers.
+    pub async fn insert(&self, timer: Timer) -> Result<u64, TimerError> {
+        let mut timers = self.inner.timers.write().await;
+        if let Some(max_timers) = self.inner.max_timers {
+            if Self::active_count(&timers) >= max_timers {
+                return Err(TimerError::capacity_exceeded(max_timers));
+            }
+        }
+
+        timer.attach_registry(Arc::downgrade(&self.inner)).await;
+        Ok(self.insert_locked(&mut timers, timer))
+    }
+
+    /// Allocates an identifier and stores `timer` under the held write lock,
+    /// so identifiers are handed out in insertion order without gaps.
+    fn insert_locked(&self, timers: &mut HashMap<u64, Timer>, timer: Timer) -> u64 {
+        let id = self.inner.next_id.fetch_add(1, Ordering::Relaxed);
+        timer.set_registry_id(id);
+        timers.insert(id, timer);
         id
     }
 
+    async fn new_timer(&self) -> Timer {
+        let timer = Timer::new_with_runtime(self.inner.runtime.clone(), true);
+        timer.attach_registry(Arc::downgrade(&self.inner)).await;
+        timer
+    }
+
+    /// Loads timer definitions from a TOML file and starts each of them.
+    ///
+    /// The file holds a `[[timer]]` table per timer:
+    ///
+    /// ```toml
+    /// [[timer]]
+    /// name = "heartbeat"       # used as the timer label
+    /// interval_ms = 1000
+    /// recurring = true         # optional, defaults to true
+    /// expiration_count = 10    # optional, recurring timers only
+    /// callback = "ping"        # name registered in the callback registry
+    /// ```
+    ///
+    /// Every definition is validated before any timer starts. Returns the
+    /// registry identifiers in file order.
+    #[cfg(feature = "toml")]
+    pub async fn load_from_toml(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        callbacks: &CallbackRegistry,
+    ) -> Result<Vec<u64>, TimerError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|err| {
+            TimerError::invalid_parameter(format!(
+                "Failed to read schedule file {}: {err}",
+                path.display()
+            ))
+        })?;
+        self.load_from_toml_str(&source, callbacks).await
+    }
+
+    /// Loads timer definitions from TOML source; see [`TimerRegistry::load_from_toml`].
+    #[cfg(feature = "toml")]
+    pub async fn load_from_toml_str(
+        &self,
+        source: &str,
+        callbacks: &CallbackRegistry,
+    ) -> Result<Vec<u64>, TimerError> {
+        let file: ScheduleFile = toml::from_str(source).map_err(|err| {
+            TimerError::invalid_parameter(format!("Invalid schedule file: {err}"))
+        })?;
+
+        let reject_duplicates = self.rejects_duplicate_schedules();
+        let registered: Vec<NamedSchedule> = if reject_duplicates {
+            let named_schedules = self.inner.named_schedules.read().await;
+            self.active_named_schedules(&named_schedules)
+                .await
+                .into_iter()
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut resolved: Vec<(String, NamedSchedule, Arc<dyn TimerCallback>)> =
+            Vec::with_capacity(file.timer.len());
+        for definition in file.timer {
+            let callback =
+                Self::resolve_callback(callbacks, &definition.callback).map_err(|err| {
+                    TimerError::invalid_parameter(format!("Timer '{}': {err}", definition.name))
+                })?;
+            if definition.interval_ms == 0 {
+                return Err(TimerError::invalid_parameter(format!(
+                    "Timer '{}' must have an interval greater than zero.",
+                    definition.name
+                )));
+            }
+
+            let interval = Duration::from_millis(definition.interval_ms);
+            let kind = if definition.recurring {
+                let mut schedule = RecurringSchedule::new(interval);
+                if let Some(expiration_count) = definition.expiration_count {
+                    schedule = schedule.with_expiration_count(expiration_count);
+                }
+                NamedScheduleKind::Recurring(schedule)
+            } else {
+                NamedScheduleKind::Once(interval)
+            };
+            let named = NamedSchedule {
+                kind,
+                callback: definition.callback,
+            };
+            if reject_duplicates
+                && registered
+                    .iter()
+                    .chain(resolved.iter().map(|(_, named, _)| named))
+                    .any(|existing| *existing == named)
+            {
+                return Err(Self::duplicate_schedule_error(&named));
+            }
+            resolved.push((definition.name, named, callback));
+        }
+
+        self.ensure_capacity(resolved.len()).await?;
+        let mut ids = Vec::with_capacity(resolved.len());
+        for (name, named, callback) in resolved {
+            let (id, _) = self.start_named(Some(name), named, callback).await?;
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
     /// Starts and registers a one-time timer.
     pub async fn start_once<F>(
         &self,
@@ -81,9 +814,30 @@ impl TimerRegistry {
     where
         F: TimerCallback + 'static,
     {
-        let timer = Timer::new_with_runtime(self.runtime.clone(), true);
+        self.ensure_capacity(1).await?;
+        let timer = self.new_timer().await;
         let _ = timer.start_once(delay, callback).await?;
-        let id = self.insert(timer.clone()).await;
+        let id = self.track_started(&timer).await?;
+        Ok((id, timer))
+    }
+
+    /// Starts and registers a timer described by a persisted configuration.
+    ///
+    /// Like the other `start_*` methods, this counts toward the limit of a
+    /// bounded registry; [`TimerRegistry::insert`] remains available for
+    /// timers started elsewhere.
+    pub async fn start_config<F>(
+        &self,
+        config: &TimerConfig,
+        callback: F,
+    ) -> Result<(u64, Timer), TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        self.ensure_capacity(1).await?;
+        let timer = self.new_timer().await;
+        let _ = timer.start_config(config, callback).await?;
+        let id = self.track_started(&timer).await?;
         Ok((id, timer))
     }
 
@@ -96,9 +850,10 @@ impl TimerRegistry {
     where
         F: TimerCallback + 'static,
     {
-        let timer = Timer::new_with_runtime(self.runtime.clone(), true);
+        self.ensure_capacity(1).await?;
+        let timer = self.new_timer().await;
         let _ = timer.start_at(deadline, callback).await?;
-        let id = self.insert(timer.clone()).await;
+        let id = self.track_started(&timer).await?;
         Ok((id, timer))
     }
 
@@ -111,20 +866,120 @@ impl TimerRegistry {
     where
         F: TimerCallback + 'static,
     {
-        let timer = Timer::new_with_runtime(self.runtime.clone(), true);
+        self.ensure_capacity(1).await?;
+        let timer = self.new_timer().await;
         let _ = timer.start_recurring(schedule, callback).await?;
-        let id = self.insert(timer.clone()).await;
+        let id = self.track_started(&timer).await?;
+        Ok((id, timer))
+    }
+
+    /// Starts and registers a recurring timer whose interval is a fraction of the base period.
+    ///
+    /// Changing the base period with [`TimerRegistry::set_base_period`] rescales
+    /// every timer started this way.
+    pub async fn start_derived<F>(
+        &self,
+        fraction: Fraction,
+        callback: F,
+    ) -> Result<(u64, Timer), TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        // Holding the derived map until the fraction is recorded makes a
+        // concurrent `set_base_period` rescale this timer as well.
+        let mut derived = self.inner.derived.write().await;
+        let base_period = self.base_period().await.ok_or_else(|| {
+            TimerError::invalid_parameter(
+                "A base period must be set before starting derived timers.",
+            )
+        })?;
+        let (id, timer) = self
+            .start_recurring(RecurringSchedule::new(fraction.of(base_period)), callback)
+            .await?;
+        derived.insert(id, fraction);
         Ok((id, timer))
     }
 
+    /// Returns the base period used by derived timers, if one has been set.
+    pub async fn base_period(&self) -> Option<Duration> {
+        *self.inner.base_period.read().await
+    }
+
+    /// Sets the base period and rescales every running derived timer.
+    ///
+    /// Derived timers that are no longer running keep their interval. If a
+    /// running timer rejects its new interval, the remaining timers are still
+    /// rescaled and the first such error is returned; the base period is
+    /// updated either way.
+    pub async fn set_base_period(&self, base_period: Duration) -> Result<(), TimerError> {
+        if base_period.is_zero() {
+            return Err(TimerError::invalid_parameter(
+                "Base period must be greater than zero.",
+            ));
+        }
+
+        *self.inner.base_period.write().await = Some(base_period);
+        let derived: Vec<(u64, Fraction)> = self
+            .inner
+            .derived
+            .read()
+            .await
+            .iter()
+            .map(|(id, fraction)| (*id, *fraction))
+            .collect();
+        let mut first_error = None;
+        for (id, fraction) in derived {
+            match self.get(id).await {
+                Some(timer) => {
+                    if let Err(err) = timer
+                        .request_adjust_interval(fraction.of(base_period))
+                        .await
+                    {
+                        if !err.is_not_running() {
+                            first_error.get_or_insert(err);
+                        }
+                    }
+                }
+                None => {
+                    self.inner.derived.write().await.remove(&id);
+                }
+            }
+        }
+
+        first_error.map_or(Ok(()), Err)
+    }
+
     /// Removes a timer from the registry and returns it.
     pub async fn remove(&self, id: u64) -> Option<Timer> {
-        self.timers.write().await.remove(&id)
+        self.inner.named_schedules.write().await.remove(&id);
+        self.inner.timers.write().await.remove(&id)
+    }
+
+    /// Stops a timer if it is active, removes it from the registry, and returns it.
+    ///
+    /// Unlike [`TimerRegistry::remove`], the removed timer does not keep running.
+    pub async fn remove_timer(&self, id: u64) -> Option<Timer> {
+        let timer = self.remove(id).await?;
+        let _ = timer.stop().await;
+        Some(timer)
+    }
+
+    /// Removes every stopped or completed timer from the registry and returns
+    /// how many were removed.
+    ///
+    /// Useful in long-lived processes to drop finished one-shot timers.
+    pub async fn prune_stopped(&self) -> usize {
+        let mut named_schedules = self.inner.named_schedules.write().await;
+        let mut timers = self.inner.timers.write().await;
+        let before = timers.len();
+        timers.retain(|_, timer| timer.try_state().is_none_or(TimerState::is_active));
+        named_schedules.retain(|id, _| timers.contains_key(id));
+        before - timers.len()
     }
 
     /// Returns true when the registry tracks the given timer identifier.
     pub async fn contains(&self, id: u64) -> bool {
-        self.timers.read().await.contains_key(&id)
+        self.inner.timers.read().await.contains_key(&id)
     }
 
     /// Stops a timer by identifier when it exists.
@@ -170,24 +1025,90 @@ impl TimerRegistry {
     }
 
     /// Stops all timers currently tracked by the registry.
-    pub async fn stop_all(&self) {
-        let timers: Vec<Timer> = self.timers.read().await.values().cloned().collect();
+    ///
+    /// Returns how many timers were running or paused and have now stopped.
+    /// Stopped timers stay tracked; use [`TimerRegistry::clear`] to drop them.
+    pub async fn stop_all(&self) -> usize {
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
+        let mut stopped = 0;
         for timer in timers {
-            let _ = timer.stop().await;
+            if timer.stop().await.is_ok() {
+                stopped += 1;
+            }
+        }
+        stopped
+    }
+
+    /// Gracefully stops all tracked timers, cancelling any that outlast `timeout`.
+    ///
+    /// Every active timer is asked to stop and may finish its current
+    /// callback. Timers still running once `timeout` has elapsed are
+    /// force-cancelled, and their identifiers are returned in ascending order
+    /// as the error.
+    pub async fn drain_with_timeout(&self, timeout: Duration) -> Result<(), Vec<u64>> {
+        let timers: Vec<(u64, Timer)> = self
+            .inner
+            .timers
+            .read()
+            .await
+            .iter()
+            .map(|(id, timer)| (*id, timer.clone()))
+            .collect();
+
+        let mut draining = Vec::with_capacity(timers.len());
+        for (id, timer) in timers {
+            if timer.request_stop().await.is_ok() {
+                draining.push((id, timer));
+            }
+        }
+
+        // A timeout beyond the clock's range waits for every timer to finish.
+        let deadline = self.inner.runtime.now().checked_add(timeout);
+        let mut aborted = Vec::new();
+        for (id, timer) in draining {
+            let finished = match deadline {
+                Some(deadline) => tokio::select! {
+                    _ = timer.join() => true,
+                    _ = self.inner.runtime.sleep_until(deadline) => false,
+                },
+                None => {
+                    let _ = timer.join().await;
+                    true
+                }
+            };
+            if !finished && timer.cancel().await.is_ok() {
+                aborted.push(id);
+            }
+        }
+
+        if aborted.is_empty() {
+            Ok(())
+        } else {
+            aborted.sort_unstable();
+            Err(aborted)
         }
     }
 
     /// Pauses all running timers currently tracked by the registry.
-    pub async fn pause_all(&self) {
-        let timers: Vec<Timer> = self.timers.read().await.values().cloned().collect();
+    ///
+    /// Returns how many timers were paused; timers that are already paused
+    /// or stopped are skipped. Every handle to a paused timer, including
+    /// those returned by [`TimerRegistry::get`], observes the new state.
+    pub async fn pause_all(&self) -> usize {
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
+        let mut paused = 0;
         for timer in timers {
-            let _ = timer.pause().await;
+            if timer.pause().await.is_ok() {
+                paused += 1;
+            }
         }
+        paused
     }
 
     /// Waits for all tracked timers that have a joinable outcome.
     pub async fn join_all(&self) -> Vec<(u64, TimerOutcome)> {
         let timers: Vec<(u64, Timer)> = self
+            .inner
             .timers
             .read()
             .await
@@ -207,23 +1128,58 @@ impl TimerRegistry {
 
     /// Cancels all timers currently tracked by the registry.
     pub async fn cancel_all(&self) {
-        let timers: Vec<Timer> = self.timers.read().await.values().cloned().collect();
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
         for timer in timers {
             let _ = timer.cancel().await;
         }
     }
 
     /// Resumes all paused timers currently tracked by the registry.
-    pub async fn resume_all(&self) {
-        let timers: Vec<Timer> = self.timers.read().await.values().cloned().collect();
+    ///
+    /// Returns how many timers were resumed; timers that are running or
+    /// stopped are skipped.
+    pub async fn resume_all(&self) -> usize {
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
+        let mut resumed = 0;
+        for timer in timers {
+            if timer.resume().await.is_ok() {
+                resumed += 1;
+            }
+        }
+        resumed
+    }
+
+    /// Fires every pending one-shot timer immediately, leaving recurring timers alone.
+    ///
+    /// Intended for tests that should not sleep through real one-shot delays.
+    pub async fn fire_all_now(&self) {
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
         for timer in timers {
-            let _ = timer.resume().await;
+            if timer.one_shot_outcome().await == Some(OneShotOutcome::Pending) {
+                let _ = timer.trigger_now().await;
+            }
+        }
+    }
+
+    /// Counts tracked one-shot timers that are armed but have not fired yet.
+    ///
+    /// Recurring timers and one-shots that fired or were stopped before
+    /// firing are not counted.
+    pub async fn pending_one_shots(&self) -> usize {
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
+        let mut pending = 0;
+        for timer in timers {
+            if timer.one_shot_outcome().await == Some(OneShotOutcome::Pending) {
+                pending += 1;
+            }
         }
+        pending
     }
 
     /// Lists all active timers.
     pub async fn active_ids(&self) -> Vec<u64> {
         let timers: Vec<(u64, Timer)> = self
+            .inner
             .timers
             .read()
             .await
@@ -233,7 +1189,7 @@ impl TimerRegistry {
 
         let mut active = Vec::new();
         for (id, timer) in timers {
-            if timer.get_state().await != TimerState::Stopped {
+            if timer.get_state().await.is_active() {
                 active.push(id);
             }
         }
@@ -241,12 +1197,28 @@ impl TimerRegistry {
     }
 
     /// Retrieves a timer by ID.
+    ///
+    /// The returned handle shares state with the tracked timer, so stopping
+    /// or adjusting it is visible through the registry.
     pub async fn get(&self, id: u64) -> Option<Timer> {
-        self.timers.read().await.get(&id).cloned()
+        self.inner.timers.read().await.get(&id).cloned()
     }
 
-    /// Returns a snapshot of a tracked timer by identifier.
-    pub async fn snapshot(&self, id: u64) -> Option<RegisteredTimer> {
+    /// Returns a non-owning handle to a tracked timer by identifier.
+    ///
+    /// The handle stops upgrading once the timer is removed from the registry
+    /// and every other strong handle has been dropped.
+    pub async fn get_weak(&self, id: u64) -> Option<WeakTimer> {
+        self.inner
+            .timers
+            .read()
+            .await
+            .get(&id)
+            .map(Timer::downgrade)
+    }
+
+    /// Returns a snapshot of a tracked timer by identifier.
+    pub async fn snapshot(&self, id: u64) -> Option<RegisteredTimer> {
         let timer = self.get(id).await?;
         Some(RegisteredTimer::from_snapshot(id, timer.snapshot().await))
     }
@@ -254,6 +1226,7 @@ impl TimerRegistry {
     /// Lists snapshots for all tracked timers.
     pub async fn list(&self) -> Vec<RegisteredTimer> {
         let timers: Vec<(u64, Timer)> = self
+            .inner
             .timers
             .read()
             .await
@@ -268,6 +1241,86 @@ impl TimerRegistry {
         listed
     }
 
+    /// Lists the state of every tracked timer without awaiting.
+    ///
+    /// Safe to call from synchronous code, including code running on a Tokio
+    /// worker thread: nothing blocks or enters a runtime. Returns `None` when
+    /// the registry is being modified concurrently; use
+    /// [`TimerRegistry::list`] for full snapshots from async code.
+    pub fn try_list_states(&self) -> Option<Vec<(u64, TimerState)>> {
+        let timers = self.inner.timers.try_read().ok()?;
+        Some(
+            timers
+                .iter()
+                .filter_map(|(id, timer)| Some((*id, timer.try_state()?)))
+                .collect(),
+        )
+    }
+
+    /// Renders a human-readable report of every tracked timer, one line each.
+    ///
+    /// Each line shows the identifier, label, state, interval, time until the
+    /// next fire, execution count, and last error, ordered by identifier.
+    /// Timers are collected before any of them is queried, so the registry
+    /// lock is never held while awaiting a timer.
+    pub async fn debug_dump(&self) -> String {
+        let mut timers: Vec<(u64, Timer)> = self
+            .inner
+            .timers
+            .read()
+            .await
+            .iter()
+            .map(|(id, timer)| (*id, timer.clone()))
+            .collect();
+        timers.sort_unstable_by_key(|(id, _)| *id);
+
+        let mut dump = format!("{} timer(s)\n", timers.len());
+        for (id, timer) in timers {
+            let snapshot = timer.snapshot().await;
+            let next_fire = match timer.time_until_next().await {
+                Some(remaining) => format!("{remaining:?}"),
+                None => "-".to_owned(),
+            };
+            let last_error = match &snapshot.statistics.last_error {
+                Some(error) => error.to_string(),
+                None => "-".to_owned(),
+            };
+            dump.push_str(&format!(
+                "#{id} {:?} state={:?} interval={:?} next_fire_in={next_fire} executions={} last_error={last_error}\n",
+                snapshot.metadata.label.as_deref().unwrap_or("-"),
+                snapshot.state,
+                snapshot.interval,
+                snapshot.statistics.execution_count,
+            ));
+        }
+        dump
+    }
+
+    /// Sums statistics and tallies states across all tracked timers.
+    ///
+    /// Timers are collected before any of them is queried, so the registry
+    /// lock is never held while awaiting a timer.
+    pub async fn aggregate_statistics(&self) -> AggregateStats {
+        let timers: Vec<Timer> = self.inner.timers.read().await.values().cloned().collect();
+
+        let mut aggregate = AggregateStats {
+            timers: timers.len(),
+            ..AggregateStats::default()
+        };
+        for timer in timers {
+            match timer.get_state().await {
+                TimerState::Running => aggregate.running += 1,
+                TimerState::Paused => aggregate.paused += 1,
+                TimerState::Stopped => aggregate.stopped += 1,
+                TimerState::Completed => aggregate.completed += 1,
+            }
+            let statistics = timer.get_statistics().await;
+            aggregate.total_executions += statistics.execution_count;
+            aggregate.total_errors += statistics.failed_executions;
+        }
+        aggregate
+    }
+
     /// Returns the identifiers for timers carrying a matching label.
     pub async fn find_by_label(&self, label: &str) -> Vec<u64> {
         let snapshots = self.list().await;
@@ -278,9 +1331,21 @@ impl TimerRegistry {
             .collect()
     }
 
+    /// Returns every tracked timer carrying a matching label, ordered by identifier.
+    ///
+    /// Labels need not be unique, so several timers may match.
+    pub async fn get_by_label(&self, label: &str) -> Vec<(u64, Timer)> {
+        let mut ids = self.find_by_label(label).await;
+        ids.sort_unstable();
+        let timers = self.inner.timers.read().await;
+        ids.into_iter()
+            .filter_map(|id| Some((id, timers.get(&id)?.clone())))
+            .collect()
+    }
+
     /// Returns the number of tracked timers.
     pub async fn len(&self) -> usize {
-        self.timers.read().await.len()
+        self.inner.timers.read().await.len()
     }
 
     /// Returns true when the registry is empty.
@@ -290,7 +1355,8 @@ impl TimerRegistry {
 
     /// Removes all tracked timers and returns the number removed.
     pub async fn clear(&self) -> usize {
-        let mut timers = self.timers.write().await;
+        self.inner.named_schedules.write().await.clear();
+        let mut timers = self.inner.timers.write().await;
         let removed = timers.len();
         timers.clear();
         removed
@@ -307,6 +1373,7 @@ impl RegisteredTimer {
             statistics: snapshot.statistics,
             last_outcome: snapshot.last_outcome,
             metadata: snapshot.metadata,
+            created_at: snapshot.created_at,
         }
     }
 }
@@ -324,6 +1391,599 @@ mod tests {
         }
     }
 
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn timers_due_together_run_one_at_a_time_in_priority_order() {
+        let registry = TimerRegistry::new();
+        registry.set_priority_ordering(true);
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut timers = Vec::new();
+        for (name, priority) in [
+            ("rebuild view", Timer::DEFAULT_PRIORITY),
+            ("refresh cache", 200),
+            ("write log", Timer::DEFAULT_PRIORITY),
+            ("audit", 10),
+        ] {
+            let order = Arc::clone(&order);
+            let (_, timer) = registry
+                .start_once(Duration::from_secs(1), move || {
+                    let order = Arc::clone(&order);
+                    async move {
+                        order.lock().unwrap().push(format!("{name} started"));
+                        yield_now().await;
+                        order.lock().unwrap().push(format!("{name} finished"));
+                        Ok(())
+                    }
+                })
+                .await
+                .unwrap();
+            timer.set_priority(priority);
+            timers.push(timer);
+        }
+        assert!(registry.orders_by_priority());
+        assert_eq!(timers[1].priority(), 200);
+
+        advance(Duration::from_secs(1)).await;
+        for timer in timers {
+            assert_eq!(
+                timer.join().await.unwrap().reason,
+                TimerFinishReason::Completed
+            );
+        }
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            [
+                "refresh cache started",
+                "refresh cache finished",
+                "rebuild view started",
+                "rebuild view finished",
+                "write log started",
+                "write log finished",
+                "audit started",
+                "audit finished",
+            ]
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn timers_due_a_moment_apart_run_in_priority_order_on_the_real_clock() {
+        let registry = TimerRegistry::new();
+        registry.set_priority_ordering(true);
+        registry.set_priority_window(Duration::from_millis(200));
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut timers = Vec::new();
+        for priority in [10, 50, 200, 120] {
+            let order = Arc::clone(&order);
+            let timer = Timer::once(Duration::from_millis(50))
+                .priority(priority)
+                .start(move || {
+                    let order = Arc::clone(&order);
+                    async move {
+                        order.lock().unwrap().push(priority);
+                        tokio::time::sleep(Duration::from_millis(5)).await;
+                        Ok(())
+                    }
+                })
+                .await
+                .unwrap();
+            registry.insert(timer.clone()).await.unwrap();
+            timers.push(timer);
+            tokio::time::sleep(Duration::from_millis(2)).await;
+        }
+
+        for timer in timers {
+            assert_eq!(
+                timer.join().await.unwrap().reason,
+                TimerFinishReason::Completed
+            );
+        }
+        assert_eq!(*order.lock().unwrap(), [200, 120, 50, 10]);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn retry_backoff_does_not_hold_the_dispatch_turn() {
+        let registry = TimerRegistry::new();
+        registry.set_priority_ordering(true);
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let attempts = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let retrying = Timer::once(Duration::from_secs(1))
+            .priority(200)
+            .max_retries(1)
+            .fixed_backoff(Duration::from_secs(10))
+            .start({
+                let order = Arc::clone(&order);
+                move || {
+                    let order = Arc::clone(&order);
+                    let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                    async move {
+                        order
+                            .lock()
+                            .unwrap()
+                            .push(format!("retrying attempt {attempt}"));
+                        if attempt == 0 {
+                            Err(TimerError::callback_failed("first attempt fails"))
+                        } else {
+                            Ok(())
+                        }
+                    }
+                }
+            })
+            .await
+            .unwrap();
+        registry.insert(retrying.clone()).await.unwrap();
+        let (_, other) = registry
+            .start_once(Duration::from_secs(1), {
+                let order = Arc::clone(&order);
+                move || {
+                    let order = Arc::clone(&order);
+                    async move {
+                        order.lock().unwrap().push("other".to_owned());
+                        Ok(())
+                    }
+                }
+            })
+            .await
+            .unwrap();
+        settle().await;
+
+        advance(Duration::from_secs(2)).await;
+        settle().await;
+        assert_eq!(other.get_statistics().await.execution_count, 1);
+
+        advance(Duration::from_secs(10)).await;
+        retrying.join().await.unwrap();
+        assert_eq!(
+            *order.lock().unwrap(),
+            ["retrying attempt 0", "other", "retrying attempt 1"]
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn try_list_states_reads_states_synchronously_inside_a_runtime() {
+        let registry = TimerRegistry::new();
+        let (running, _) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (paused, paused_timer) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        paused_timer.pause().await.unwrap();
+
+        let mut states = registry.try_list_states().unwrap();
+        states.sort_by_key(|(id, _)| *id);
+        assert_eq!(
+            states,
+            vec![(running, TimerState::Running), (paused, TimerState::Paused)]
+        );
+
+        let guard = registry.inner.timers.write().await;
+        assert_eq!(registry.try_list_states(), None);
+        drop(guard);
+        registry.stop_all().await;
+    }
+
+    async fn jittered_fire_offsets(registry: TimerRegistry) -> Vec<Duration> {
+        let started_at = Instant::now();
+        let offsets = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&offsets);
+        let (_, timer) = registry
+            .start_recurring(
+                RecurringSchedule::new(Duration::from_secs(1))
+                    .with_jitter(Duration::from_millis(500))
+                    .with_expiration_count(5),
+                move || {
+                    let recorded = Arc::clone(&recorded);
+                    async move {
+                        recorded.lock().unwrap().push(started_at.elapsed());
+                        Ok(())
+                    }
+                },
+            )
+            .await
+            .unwrap();
+        timer.join().await.unwrap();
+        let offsets = offsets.lock().unwrap().clone();
+        offsets
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn registries_with_the_same_seed_produce_the_same_jitter() {
+        let first = jittered_fire_offsets(TimerRegistry::with_seed(7)).await;
+        let second = jittered_fire_offsets(TimerRegistry::with_seed(7)).await;
+        let other = jittered_fire_offsets(TimerRegistry::with_seed(8)).await;
+
+        assert_eq!(first.len(), 5);
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn start_config_builds_starts_and_tracks_a_named_timer() {
+        let registry = TimerRegistry::with_capacity(1);
+        let config = TimerConfig {
+            interval: Duration::from_secs(5),
+            recurring: true,
+            expiration_count: Some(2),
+            name: Some("sync".to_string()),
+        };
+
+        let invalid = TimerConfig {
+            recurring: false,
+            ..config.clone()
+        };
+        assert!(registry
+            .start_config(&invalid, || async { Ok(()) })
+            .await
+            .err()
+            .unwrap()
+            .is_invalid_parameter());
+        assert!(registry.is_empty().await);
+
+        let (id, timer) = registry
+            .start_config(&config, || async { Ok(()) })
+            .await
+            .unwrap();
+        assert_eq!(registry.find_by_label("sync").await, vec![id]);
+        assert_eq!(timer.to_config().await, config);
+        assert!(registry
+            .start_config(&config, || async { Ok(()) })
+            .await
+            .err()
+            .unwrap()
+            .is_capacity_exceeded());
+
+        settle().await;
+        for _ in 0..2 {
+            advance(Duration::from_secs(5)).await;
+            settle().await;
+        }
+        let outcome = timer.join().await.unwrap();
+        assert_eq!(outcome.reason, TimerFinishReason::Completed);
+        assert_eq!(outcome.statistics.execution_count, 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn bounded_registry_rejects_timers_beyond_its_capacity() {
+        let mut callbacks = CallbackRegistry::new();
+        callbacks.register("ping", || async { Ok(()) });
+        let registry = TimerRegistry::with_capacity(2);
+        assert_eq!(registry.max_timers(), Some(2));
+
+        for seconds in 1..=2 {
+            registry
+                .start_by_name(
+                    "ping",
+                    RecurringSchedule::new(Duration::from_secs(seconds)),
+                    &callbacks,
+                )
+                .await
+                .unwrap();
+        }
+
+        let error = registry
+            .start_by_name(
+                "ping",
+                RecurringSchedule::new(Duration::from_secs(3)),
+                &callbacks,
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(error.is_capacity_exceeded());
+        assert_eq!(error.capacity_limit(), Some(2));
+        assert!(registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .err()
+            .unwrap()
+            .is_capacity_exceeded());
+        assert!(registry
+            .insert(Timer::new())
+            .await
+            .unwrap_err()
+            .is_capacity_exceeded());
+        assert_eq!(registry.len().await, 2);
+
+        let id = registry.active_ids().await[0];
+        registry.remove_timer(id).await.unwrap();
+        assert!(registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .is_ok());
+        registry.stop_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn stopped_and_completed_timers_free_their_capacity_slot() {
+        let registry = TimerRegistry::with_capacity(2);
+        let (_, one_shot) = registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .unwrap();
+        let (_, recurring) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        recurring.pause().await.unwrap();
+        assert!(registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .err()
+            .unwrap()
+            .is_capacity_exceeded());
+
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        assert_eq!(one_shot.get_state().await, TimerState::Completed);
+        registry
+            .start_once(Duration::from_secs(5), || async { Ok(()) })
+            .await
+            .unwrap();
+
+        recurring.stop().await.unwrap();
+        registry.insert(Timer::new()).await.unwrap();
+        assert_eq!(registry.len().await, 4);
+        registry.stop_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn pending_one_shots_decreases_as_one_shots_fire() {
+        let registry = TimerRegistry::new();
+        for seconds in 1..=3 {
+            registry
+                .start_once(Duration::from_secs(seconds), || async { Ok(()) })
+                .await
+                .unwrap();
+        }
+        let (_, cancelled) = registry
+            .start_once(Duration::from_secs(10), || async { Ok(()) })
+            .await
+            .unwrap();
+        registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        settle().await;
+        assert_eq!(registry.pending_one_shots().await, 4);
+
+        cancelled.cancel().await.unwrap();
+        assert_eq!(registry.pending_one_shots().await, 3);
+
+        for expected in [2, 1, 0] {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+            assert_eq!(registry.pending_one_shots().await, expected);
+        }
+        registry.stop_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn remove_timer_stops_and_prune_stopped_drops_finished_timers() {
+        let registry = TimerRegistry::new();
+        let (recurring, recurring_timer) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (kept, _) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let mut finished = Vec::new();
+        for _ in 0..2 {
+            let (id, _) = registry
+                .start_once(Duration::from_millis(10), || async { Ok(()) })
+                .await
+                .unwrap();
+            finished.push(id);
+        }
+        settle().await;
+        advance(Duration::from_millis(10)).await;
+        settle().await;
+
+        let removed = registry.remove_timer(recurring).await.unwrap();
+        assert_eq!(removed.get_state().await, TimerState::Stopped);
+        assert_eq!(
+            recurring_timer.join().await.unwrap().reason,
+            TimerFinishReason::Stopped
+        );
+        assert!(registry.remove_timer(recurring).await.is_none());
+
+        assert_eq!(registry.prune_stopped().await, 2);
+        assert_eq!(registry.len().await, 1);
+        assert!(registry.contains(kept).await);
+        assert!(!registry.contains(finished[0]).await);
+        assert_eq!(registry.prune_stopped().await, 0);
+        registry.stop_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn drain_with_timeout_returns_the_ids_of_hanging_timers() {
+        let registry = TimerRegistry::new();
+        let (quick_id, quick) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (hanging_id, hanging) = registry
+            .start_once(Duration::from_secs(1), || async {
+                std::future::pending::<()>().await;
+                Ok(())
+            })
+            .await
+            .unwrap();
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        assert_eq!(hanging.get_statistics().await.execution_count, 0);
+
+        let started = Instant::now();
+        assert_eq!(
+            registry.drain_with_timeout(Duration::from_secs(5)).await,
+            Err(vec![hanging_id])
+        );
+        assert_eq!(started.elapsed(), Duration::from_secs(5));
+        assert_eq!(
+            quick.join().await.unwrap().reason,
+            TimerFinishReason::Stopped
+        );
+        assert_eq!(
+            hanging.join().await.unwrap().reason,
+            TimerFinishReason::Cancelled
+        );
+        assert_ne!(quick_id, hanging_id);
+
+        assert_eq!(
+            registry.drain_with_timeout(Duration::from_secs(5)).await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn drain_with_an_unbounded_timeout_waits_for_every_timer() {
+        let registry = TimerRegistry::new();
+        let (_, slow) = registry
+            .start_once(Duration::from_secs(1), || async {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                Ok(())
+            })
+            .await
+            .unwrap();
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+
+        assert_eq!(registry.drain_with_timeout(Duration::MAX).await, Ok(()));
+        let outcome = slow.join().await.unwrap();
+        assert_eq!(outcome.reason, TimerFinishReason::Stopped);
+        assert_eq!(outcome.statistics.successful_executions, 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn debug_dump_lists_every_timer_with_its_state() {
+        let registry = TimerRegistry::new();
+        let heartbeat = Timer::recurring(RecurringSchedule::new(Duration::from_secs(2)))
+            .label("heartbeat")
+            .start(|| async { Ok(()) })
+            .await
+            .unwrap();
+        let heartbeat_id = registry.insert(heartbeat).await.unwrap();
+        let (failing_id, _failing) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Err(TimerError::callback_failed("backend down"))
+            })
+            .await
+            .unwrap();
+        let (paused_id, paused) = registry
+            .start_once(Duration::from_secs(30), || async { Ok(()) })
+            .await
+            .unwrap();
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        paused.pause().await.unwrap();
+
+        let dump = registry.debug_dump().await;
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], "3 timer(s)");
+
+        let line_for = |id: u64| {
+            let prefix = format!("#{id} ");
+            *lines.iter().find(|line| line.starts_with(&prefix)).unwrap()
+        };
+        let heartbeat_line = line_for(heartbeat_id);
+        assert!(heartbeat_line.contains("\"heartbeat\""));
+        assert!(heartbeat_line.contains("state=Running"));
+        assert!(heartbeat_line.contains("interval=2s"));
+        assert!(heartbeat_line.contains("next_fire_in=1s"));
+        assert!(heartbeat_line.contains("executions=0"));
+
+        let failing_line = line_for(failing_id);
+        assert!(failing_line.contains("state=Running"));
+        assert!(failing_line.contains("executions=1"));
+        assert!(failing_line.contains("backend down"));
+
+        let paused_line = line_for(paused_id);
+        assert!(paused_line.contains("state=Paused"));
+        assert!(paused_line.contains("next_fire_in=-"));
+        assert!(paused_line.contains("last_error=-"));
+
+        registry.cancel_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn stop_all_stops_every_active_timer_and_counts_them() {
+        let registry = TimerRegistry::new();
+        for seconds in 1..=3 {
+            registry
+                .start_recurring(
+                    RecurringSchedule::new(Duration::from_secs(seconds)),
+                    || async { Ok(()) },
+                )
+                .await
+                .unwrap();
+        }
+        let (_, once) = registry
+            .start_once(Duration::from_millis(10), || async { Ok(()) })
+            .await
+            .unwrap();
+        advance(Duration::from_millis(10)).await;
+        settle().await;
+        once.join().await.unwrap();
+
+        assert_eq!(registry.active_ids().await.len(), 3);
+        assert_eq!(registry.stop_all().await, 3);
+        assert!(registry.active_ids().await.is_empty());
+        assert_eq!(registry.stop_all().await, 0);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn handles_from_get_share_state_with_the_registry() {
+        let registry = TimerRegistry::new();
+        let (id, _) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(2)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        settle().await;
+
+        let handle = registry.get(id).await.unwrap();
+        handle
+            .adjust_interval(Duration::from_secs(5))
+            .await
+            .unwrap();
+        handle.stop().await.unwrap();
+
+        let listed = registry.list().await;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].state, TimerState::Stopped);
+        assert_eq!(listed[0].interval, Duration::from_secs(5));
+        assert_eq!(
+            listed[0]
+                .last_outcome
+                .as_ref()
+                .map(|outcome| outcome.reason),
+            Some(TimerFinishReason::Stopped)
+        );
+    }
+
     #[tokio::test(flavor = "current_thread", start_paused = true)]
     async fn registry_start_helpers_are_easy_to_use() {
         let registry = TimerRegistry::new();
@@ -397,4 +2057,782 @@ mod tests {
             TimerFinishReason::Completed
         );
     }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn pause_all_and_resume_all_count_the_timers_they_transition() {
+        let registry = TimerRegistry::new();
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            let (id, _) = registry
+                .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                    Ok(())
+                })
+                .await
+                .unwrap();
+            ids.push(id);
+        }
+        let stopped = registry.insert(Timer::new()).await.unwrap();
+        settle().await;
+
+        registry.get(ids[0]).await.unwrap().pause().await.unwrap();
+        assert_eq!(registry.pause_all().await, 2);
+        assert_eq!(registry.pause_all().await, 0);
+        settle().await;
+        for id in &ids {
+            assert_eq!(
+                registry.get(*id).await.unwrap().get_state().await,
+                TimerState::Paused
+            );
+        }
+
+        advance(Duration::from_secs(3)).await;
+        settle().await;
+        for id in &ids {
+            let timer = registry.get(*id).await.unwrap();
+            assert_eq!(timer.get_statistics().await.execution_count, 0);
+        }
+
+        assert_eq!(registry.resume_all().await, 3);
+        assert_eq!(registry.resume_all().await, 0);
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        for id in &ids {
+            let timer = registry.get(*id).await.unwrap();
+            assert_eq!(timer.get_state().await, TimerState::Running);
+            assert_eq!(timer.get_statistics().await.execution_count, 1);
+        }
+        assert_eq!(
+            registry.get(stopped).await.unwrap().get_state().await,
+            TimerState::Stopped
+        );
+
+        registry.cancel_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn get_by_label_returns_every_matching_timer() {
+        let registry = TimerRegistry::new();
+        let mut heartbeat_ids = Vec::new();
+        for _ in 0..2 {
+            let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+                .label("heartbeat")
+                .start(|| async { Ok(()) })
+                .await
+                .unwrap();
+            heartbeat_ids.push(registry.insert(timer).await.unwrap());
+        }
+        let (other_id, other) = registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .unwrap();
+        other.set_label("cleanup").await;
+
+        let heartbeats = registry.get_by_label("heartbeat").await;
+        assert_eq!(
+            heartbeats.iter().map(|(id, _)| *id).collect::<Vec<_>>(),
+            heartbeat_ids
+        );
+        for (_, timer) in &heartbeats {
+            assert_eq!(timer.label().await.as_deref(), Some("heartbeat"));
+        }
+        let cleanup = registry.get_by_label("cleanup").await;
+        assert_eq!(cleanup.len(), 1);
+        assert_eq!(cleanup[0].0, other_id);
+        assert!(registry.get_by_label("missing").await.is_empty());
+
+        registry.cancel_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn registry_limits_concurrent_callback_executions() {
+        use std::sync::atomic::AtomicUsize;
+
+        let registry = TimerRegistry::with_max_concurrency(2);
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let mut timers = Vec::new();
+
+        for _ in 0..8 {
+            let active = Arc::clone(&active);
+            let peak = Arc::clone(&peak);
+            let (_, timer) = registry
+                .start_once(Duration::from_secs(1), move || {
+                    let active = Arc::clone(&active);
+                    let peak = Arc::clone(&peak);
+                    async move {
+                        let running = active.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(running, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_secs(1)).await;
+                        active.fetch_sub(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                })
+                .await
+                .unwrap();
+            timers.push(timer);
+        }
+
+        for timer in timers {
+            let outcome = timer.join().await.unwrap();
+            assert_eq!(outcome.statistics.successful_executions, 1);
+        }
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn retry_backoff_does_not_hold_a_concurrency_slot() {
+        use std::sync::atomic::AtomicUsize;
+
+        let registry = TimerRegistry::with_max_concurrency(1);
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_for_callback = Arc::clone(&attempts);
+        let retrying = Timer::once(Duration::from_secs(1))
+            .max_retries(1)
+            .fixed_backoff(Duration::from_secs(10))
+            .start(move || {
+                let attempts = Arc::clone(&attempts_for_callback);
+                async move {
+                    if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                        Err(TimerError::callback_failed("first attempt fails"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            })
+            .await
+            .unwrap();
+        registry.insert(retrying.clone()).await.unwrap();
+        let (_, other) = registry
+            .start_once(Duration::from_secs(2), || async { Ok(()) })
+            .await
+            .unwrap();
+        settle().await;
+
+        for _ in 0..2 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert_eq!(other.get_statistics().await.execution_count, 1);
+
+        advance(Duration::from_secs(10)).await;
+        settle().await;
+        let outcome = retrying.join().await.unwrap();
+        assert_eq!(outcome.statistics.successful_executions, 1);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn fire_all_now_runs_pending_one_shots_without_waiting() {
+        use std::sync::atomic::AtomicUsize;
+
+        let registry = TimerRegistry::new();
+        let executions = Arc::new(AtomicUsize::new(0));
+        let mut one_shots = Vec::new();
+        for _ in 0..3 {
+            let executions = Arc::clone(&executions);
+            let (_, timer) = registry
+                .start_once(Duration::from_secs(3_600), move || {
+                    let executions = Arc::clone(&executions);
+                    async move {
+                        executions.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    }
+                })
+                .await
+                .unwrap();
+            one_shots.push(timer);
+        }
+        let (_, recurring) = registry
+            .start_recurring(
+                RecurringSchedule::new(Duration::from_secs(3_600)),
+                || async { Ok(()) },
+            )
+            .await
+            .unwrap();
+        settle().await;
+        let started_at = Instant::now();
+
+        registry.fire_all_now().await;
+        settle().await;
+
+        for timer in one_shots {
+            assert_eq!(
+                timer.join().await.unwrap().reason,
+                TimerFinishReason::Completed
+            );
+        }
+        assert_eq!(executions.load(Ordering::SeqCst), 3);
+        assert_eq!(Instant::now(), started_at);
+        assert_eq!(recurring.get_statistics().await.execution_count, 0);
+        assert_eq!(recurring.get_state().await, TimerState::Running);
+
+        let _ = recurring.cancel().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn callbacks_can_stop_sibling_timers_through_the_context() {
+        use crate::timer::{ContextCallback, TimerContext};
+
+        let registry = TimerRegistry::new();
+        let (sibling_id, sibling) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(10)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (_, stopper) = registry
+            .start_once(
+                Duration::from_secs(1),
+                ContextCallback::new(move |context: TimerContext| async move {
+                    let registry = context.registry().expect("timer is registered");
+                    registry.stop(sibling_id).await?;
+                    Ok(())
+                }),
+            )
+            .await
+            .unwrap();
+        settle().await;
+
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+
+        assert_eq!(
+            stopper.join().await.unwrap().reason,
+            TimerFinishReason::Completed
+        );
+        assert_eq!(stopper.last_result().await, Some(Ok(())));
+        assert_eq!(
+            sibling.join().await.unwrap().reason,
+            TimerFinishReason::Stopped
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn derived_timers_rescale_with_the_base_period() {
+        let registry = TimerRegistry::new();
+        assert!(registry
+            .start_derived(Fraction::new(1, 2).unwrap(), || async { Ok(()) })
+            .await
+            .err()
+            .unwrap()
+            .is_invalid_parameter());
+
+        registry
+            .set_base_period(Duration::from_secs(10))
+            .await
+            .unwrap();
+        let (_, half) = registry
+            .start_derived(Fraction::new(1, 2).unwrap(), || async { Ok(()) })
+            .await
+            .unwrap();
+        let (_, double) = registry
+            .start_derived(Fraction::new(2, 1).unwrap(), || async { Ok(()) })
+            .await
+            .unwrap();
+        assert_eq!(half.get_interval().await, Duration::from_secs(5));
+        assert_eq!(double.get_interval().await, Duration::from_secs(20));
+        settle().await;
+
+        registry
+            .set_base_period(Duration::from_secs(2))
+            .await
+            .unwrap();
+        settle().await;
+        assert_eq!(half.get_interval().await, Duration::from_secs(1));
+        assert_eq!(double.get_interval().await, Duration::from_secs(4));
+
+        for _ in 0..4 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+        assert_eq!(half.get_statistics().await.execution_count, 4);
+        assert_eq!(double.get_statistics().await.execution_count, 1);
+
+        registry.cancel_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn set_base_period_reports_timers_that_reject_their_new_interval() {
+        let registry = TimerRegistry::new();
+        registry
+            .set_base_period(Duration::from_secs(10))
+            .await
+            .unwrap();
+        let (_, half) = registry
+            .start_derived(Fraction::new(1, 2).unwrap(), || async { Ok(()) })
+            .await
+            .unwrap();
+        let (_, double) = registry
+            .start_derived(Fraction::new(2, 1).unwrap(), || async { Ok(()) })
+            .await
+            .unwrap();
+        let (_, stopped) = registry
+            .start_derived(Fraction::new(1, 1).unwrap(), || async { Ok(()) })
+            .await
+            .unwrap();
+        stopped.stop().await.unwrap();
+        settle().await;
+
+        // Half of one nanosecond rounds down to a zero interval.
+        let error = registry
+            .set_base_period(Duration::from_nanos(1))
+            .await
+            .unwrap_err();
+        assert!(error.is_invalid_parameter());
+        assert_eq!(registry.base_period().await, Some(Duration::from_nanos(1)));
+        assert_eq!(half.get_interval().await, Duration::from_secs(5));
+        assert_eq!(double.get_interval().await, Duration::from_nanos(2));
+        assert_eq!(stopped.get_interval().await, Duration::from_secs(10));
+
+        registry.cancel_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn weak_handles_stop_upgrading_after_removal() {
+        let registry = TimerRegistry::new();
+        let (timer_id, timer) = registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .unwrap();
+        let weak = registry.get_weak(timer_id).await.unwrap();
+        assert!(weak.upgrade().is_some());
+
+        let _ = timer.cancel().await.unwrap();
+        drop(timer);
+        assert!(weak.upgrade().is_some());
+
+        let removed = registry.remove(timer_id).await;
+        drop(removed);
+        assert!(weak.upgrade().is_none());
+        assert!(registry.get_weak(timer_id).await.is_none());
+    }
+
+    async fn start_panicking_timer(
+        registry: &TimerRegistry,
+        schedule: Option<RecurringSchedule>,
+    ) -> (Timer, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::atomic::AtomicUsize;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let calls_for_callback = Arc::clone(&calls);
+        let callback = move || {
+            let calls = Arc::clone(&calls_for_callback);
+            async move {
+                if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    panic!("first execution panics");
+                }
+                Ok(())
+            }
+        };
+        let (_, timer) = match schedule {
+            Some(schedule) => registry.start_recurring(schedule, callback).await,
+            None => registry.start_once(Duration::from_secs(1), callback).await,
+        }
+        .unwrap();
+        settle().await;
+        (timer, calls)
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn panic_policy_stop_ends_the_run() {
+        let registry = TimerRegistry::new();
+        assert_eq!(registry.panic_policy().await, PanicPolicy::Stop);
+        let (timer, _) = start_panicking_timer(
+            &registry,
+            Some(RecurringSchedule::new(Duration::from_secs(1))),
+        )
+        .await;
+
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+
+        let outcome = timer.join().await.unwrap();
+        assert_eq!(outcome.reason, TimerFinishReason::Stopped);
+        assert_eq!(outcome.statistics.failed_executions, 1);
+        assert_eq!(
+            outcome
+                .statistics
+                .last_error
+                .unwrap()
+                .callback_panic_message(),
+            Some("first execution panics")
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn panic_policy_ignore_keeps_the_timer_running() {
+        let registry = TimerRegistry::new();
+        registry.set_panic_policy(PanicPolicy::Ignore).await;
+        let (timer, calls) = start_panicking_timer(
+            &registry,
+            Some(RecurringSchedule::new(Duration::from_secs(1))),
+        )
+        .await;
+
+        for _ in 0..3 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+
+        let statistics = timer.get_statistics().await;
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(statistics.failed_executions, 1);
+        assert_eq!(statistics.successful_executions, 2);
+        assert_eq!(timer.get_state().await, TimerState::Running);
+        let _ = timer.cancel().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn panic_policy_restart_reruns_the_schedule() {
+        let registry = TimerRegistry::new();
+        registry.set_panic_policy(PanicPolicy::Restart).await;
+        let (timer, calls) = start_panicking_timer(
+            &registry,
+            Some(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2)),
+        )
+        .await;
+
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(timer.get_state().await, TimerState::Running);
+
+        for _ in 0..2 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+        let outcome = timer.join().await.unwrap();
+        assert_eq!(outcome.reason, TimerFinishReason::Completed);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        // The run's statistics span the restart.
+        assert_eq!(outcome.statistics.execution_count, 3);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn panic_policy_restart_completes_a_panicking_one_shot() {
+        let registry = TimerRegistry::new();
+        registry.set_panic_policy(PanicPolicy::Restart).await;
+        let (timer, calls) = start_panicking_timer(&registry, None).await;
+
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+
+        let outcome = timer.join().await.unwrap();
+        assert_eq!(outcome.reason, TimerFinishReason::Completed);
+        assert_eq!(outcome.statistics.failed_executions, 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn aggregate_statistics_sums_executions_and_tallies_states() {
+        let registry = TimerRegistry::new();
+        let (_, healthy) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (_, failing) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Err(TimerError::callback_failed("boom"))
+            })
+            .await
+            .unwrap();
+        let (_, once) = registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .unwrap();
+        settle().await;
+
+        for _ in 0..2 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+        once.join().await.unwrap();
+        failing.pause().await.unwrap();
+
+        assert_eq!(
+            registry.aggregate_statistics().await,
+            AggregateStats {
+                timers: 3,
+                running: 1,
+                paused: 1,
+                stopped: 0,
+                completed: 1,
+                total_executions: 5,
+                total_errors: 2,
+            }
+        );
+
+        let _ = healthy.cancel().await.unwrap();
+        let _ = failing.cancel().await.unwrap();
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn active_ids_and_pruning_treat_completed_timers_as_inactive() {
+        let registry = TimerRegistry::new();
+        let (once_id, _) = registry
+            .start_once(Duration::from_secs(1), || async { Ok(()) })
+            .await
+            .unwrap();
+        let (stopped_id, stopped) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        let (running_id, _) = registry
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+                Ok(())
+            })
+            .await
+            .unwrap();
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        let _ = stopped.stop().await.unwrap();
+
+        assert_eq!(
+            registry.get(once_id).await.unwrap().get_state().await,
+            TimerState::Completed
+        );
+        assert_eq!(registry.active_ids().await, vec![running_id]);
+        assert_eq!(registry.prune_stopped().await, 2);
+        assert!(!registry.contains(once_id).await);
+        assert!(!registry.contains(stopped_id).await);
+
+        registry.cancel_all().await;
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn global_rate_limit_caps_aggregate_executions() {
+        use std::sync::atomic::AtomicUsize;
+
+        const PER_SECOND: u32 = 50;
+
+        let registry = TimerRegistry::new();
+        registry.set_global_rate_limit(PER_SECOND).await.unwrap();
+        assert_eq!(registry.global_rate_limit().await, Some(PER_SECOND));
+
+        let executions = Arc::new(AtomicUsize::new(0));
+        let mut timers = Vec::new();
+        for _ in 0..20 {
+            let executions = Arc::clone(&executions);
+            let (_, timer) = registry
+                .start_recurring(
+                    RecurringSchedule::new(Duration::from_millis(10)),
+                    move || {
+                        let executions = Arc::clone(&executions);
+                        async move {
+                            executions.fetch_add(1, Ordering::SeqCst);
+                            Ok(())
+                        }
+                    },
+                )
+                .await
+                .unwrap();
+            timers.push(timer);
+        }
+        settle().await;
+
+        for _ in 0..200 {
+            advance(Duration::from_millis(10)).await;
+            settle().await;
+        }
+
+        // Unthrottled, the timers would execute 4,000 times in two seconds.
+        let executed = executions.load(Ordering::SeqCst);
+        assert!(executed <= 2 * PER_SECOND as usize + 1, "{executed}");
+        assert!(executed >= 2 * PER_SECOND as usize - 5, "{executed}");
+
+        for timer in timers {
+            let _ = timer.cancel().await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn global_rate_limit_rejects_zero_and_can_be_cleared() {
+        let registry = TimerRegistry::new();
+        assert!(registry
+            .set_global_rate_limit(0)
+            .await
+            .unwrap_err()
+            .is_invalid_parameter());
+
+        registry.set_global_rate_limit(10).await.unwrap();
+        registry.clear_global_rate_limit().await;
+        assert_eq!(registry.global_rate_limit().await, None);
+    }
+
+    #[cfg(feature = "toml")]
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn load_from_toml_starts_every_defined_timer() {
+        use std::sync::atomic::AtomicUsize;
+
+        let pings = Arc::new(AtomicUsize::new(0));
+        let cleanups = Arc::new(AtomicUsize::new(0));
+        let mut callbacks = CallbackRegistry::new();
+        for (name, counter) in [("ping", &pings), ("cleanup", &cleanups)] {
+            let counter = Arc::clone(counter);
+            callbacks.register(name, move || {
+                let counter = Arc::clone(&counter);
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            });
+        }
+
+        let path =
+            std::env::temp_dir().join(format!("timer-lib-schedule-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"
+[[timer]]
+name = "heartbeat"
+interval_ms = 1000
+expiration_count = 2
+callback = "ping"
+
+[[timer]]
+name = "cleanup"
+interval_ms = 500
+recurring = false
+callback = "cleanup"
+"#,
+        )
+        .unwrap();
+
+        let registry = TimerRegistry::new();
+        let ids = registry.load_from_toml(&path, &callbacks).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(ids.len(), 2);
+        assert_eq!(registry.find_by_label("heartbeat").await, vec![ids[0]]);
+        assert_eq!(registry.find_by_label("cleanup").await, vec![ids[1]]);
+        settle().await;
+
+        for _ in 0..4 {
+            advance(Duration::from_millis(500)).await;
+            settle().await;
+        }
+
+        assert_eq!(pings.load(Ordering::SeqCst), 2);
+        assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+        assert!(registry.active_ids().await.is_empty());
+    }
+
+    #[cfg(feature = "toml")]
+    #[tokio::test]
+    async fn load_from_toml_rejects_unknown_callbacks_before_starting_timers() {
+        let mut callbacks = CallbackRegistry::new();
+        callbacks.register("ping", || async { Ok(()) });
+
+        let registry = TimerRegistry::new();
+        let error = registry
+            .load_from_toml_str(
+                r#"
+[[timer]]
+name = "ok"
+interval_ms = 1000
+callback = "ping"
+
+[[timer]]
+name = "typo"
+interval_ms = 1000
+callback = "pnig"
+"#,
+                &callbacks,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(error.is_invalid_parameter());
+        assert!(error.to_string().contains("pnig"));
+        assert!(registry.is_empty().await);
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn start_by_name_rejects_duplicate_schedules_in_strict_mode() {
+        let mut callbacks = CallbackRegistry::new();
+        callbacks.register("sync", || async { Ok(()) });
+        callbacks.register("report", || async { Ok(()) });
+        let schedule = RecurringSchedule::new(Duration::from_secs(60));
+
+        let registry = TimerRegistry::new();
+        registry.set_reject_duplicate_schedules(true);
+        let (id, first) = registry
+            .start_by_name("sync", schedule, &callbacks)
+            .await
+            .unwrap();
+
+        let error = registry
+            .start_by_name("sync", schedule, &callbacks)
+            .await
+            .err()
+            .unwrap();
+        assert!(error.is_invalid_parameter());
+        assert_eq!(registry.len().await, 1);
+
+        // A different callback or schedule is not a duplicate.
+        let (_, report) = registry
+            .start_by_name("report", schedule, &callbacks)
+            .await
+            .unwrap();
+        let (_, faster_sync) = registry
+            .start_by_name(
+                "sync",
+                RecurringSchedule::new(Duration::from_secs(30)),
+                &callbacks,
+            )
+            .await
+            .unwrap();
+
+        // Removing the original frees its schedule again.
+        let _ = first.cancel().await.unwrap();
+        registry.remove(id).await;
+        let (_, replacement) = registry
+            .start_by_name("sync", schedule, &callbacks)
+            .await
+            .unwrap();
+
+        // So does stopping a timer that is still tracked.
+        let _ = report.stop().await.unwrap();
+        let (_, restarted_report) = registry
+            .start_by_name("report", schedule, &callbacks)
+            .await
+            .unwrap();
+
+        for timer in [faster_sync, replacement, restarted_report] {
+            let _ = timer.cancel().await.unwrap();
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread", start_paused = true)]
+    async fn start_by_name_allows_duplicate_schedules_by_default() {
+        let mut callbacks = CallbackRegistry::new();
+        callbacks.register("sync", || async { Ok(()) });
+        let schedule = RecurringSchedule::new(Duration::from_secs(60));
+
+        let registry = TimerRegistry::new();
+        assert!(!registry.rejects_duplicate_schedules());
+        let (_, first) = registry
+            .start_by_name("sync", schedule, &callbacks)
+            .await
+            .unwrap();
+        let (_, second) = registry
+            .start_by_name("sync", schedule, &callbacks)
+            .await
+            .unwrap();
+        assert!(registry
+            .start_by_name("missing", schedule, &callbacks)
+            .await
+            .err()
+            .unwrap()
+            .is_invalid_parameter());
+
+        let _ = first.cancel().await.unwrap();
+        let _ = second.cancel().await.unwrap();
+    }
 }
@@ -0,0 +1,186 @@
+use std::future::Future;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::time::Instant;
+
+use super::{TimerCallback, TimerContext};
+use crate::errors::TimerError;
+
+/// Observable state of a [`CircuitBreaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CircuitState {
+    /// Calls pass through to the wrapped callback.
+    Closed,
+    /// Calls are short-circuited until the cooldown elapses.
+    Open,
+    /// The next call is a trial that decides whether the circuit closes again.
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BreakerState {
+    Closed { consecutive_failures: usize },
+    Open { opened_at: Instant },
+    HalfOpen,
+}
+
+struct BreakerShared {
+    state: Mutex<BreakerState>,
+    short_circuited: AtomicU64,
+}
+
+/// Wraps a callback so repeated failures stop calling it for a while.
+///
+/// The circuit opens after `failure_threshold` consecutive failures. While
+/// open, executions return `Ok(())` without calling the wrapped callback and
+/// are counted by [`CircuitBreaker::short_circuited_calls`]. Once `cooldown`
+/// has elapsed the circuit half-opens: the next execution is a trial that
+/// closes the circuit on success and reopens it on failure.
+///
+/// Clones share state, so keep a clone to observe a breaker after handing it
+/// to a timer.
+pub struct CircuitBreaker<C> {
+    callback: Arc<C>,
+    failure_threshold: usize,
+    cooldown: Duration,
+    shared: Arc<BreakerShared>,
+}
+
+impl<C> Clone for CircuitBreaker<C> {
+    fn clone(&self) -> Self {
+        Self {
+            callback: Arc::clone(&self.callback),
+            failure_threshold: self.failure_threshold,
+            cooldown: self.cooldown,
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<C> CircuitBreaker<C> {
+    /// Wraps `callback` with a breaker that opens after `failure_threshold`
+    /// consecutive failures and half-opens after `cooldown`.
+    pub fn new(
+        callback: C,
+        failure_threshold: usize,
+        cooldown: Duration,
+    ) -> Result<Self, TimerError> {
+        if failure_threshold == 0 {
+            return Err(TimerError::invalid_parameter(
+                "Circuit breaker failure threshold must be greater than zero.",
+            ));
+        }
+
+        Ok(Self {
+            callback: Arc::new(callback),
+            failure_threshold,
+            cooldown,
+            shared: Arc::new(BreakerShared {
+                state: Mutex::new(BreakerState::Closed {
+                    consecutive_failures: 0,
+                }),
+                short_circuited: AtomicU64::new(0),
+            }),
+        })
+    }
+
+    /// Returns the number of consecutive failures that opens the circuit.
+    pub fn failure_threshold(&self) -> usize {
+        self.failure_threshold
+    }
+
+    /// Returns how long the circuit stays open before half-opening.
+    pub fn cooldown(&self) -> Duration {
+        self.cooldown
+    }
+
+    /// Returns the current circuit state.
+    pub fn state(&self) -> CircuitState {
+        match *self.lock_state() {
+            BreakerState::Closed { .. } => CircuitState::Closed,
+            BreakerState::Open { .. } => CircuitState::Open,
+            BreakerState::HalfOpen => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Returns how many executions were skipped while the circuit was open.
+    pub fn short_circuited_calls(&self) -> u64 {
+        self.shared.short_circuited.load(Ordering::SeqCst)
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.shared
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns `true` when the call may reach the wrapped callback.
+    fn admit(&self, now: Instant) -> bool {
+        let mut state = self.lock_state();
+        if let BreakerState::Open { opened_at } = *state {
+            if now.saturating_duration_since(opened_at) < self.cooldown {
+                self.shared.short_circuited.fetch_add(1, Ordering::SeqCst);
+                return false;
+            }
+            *state = BreakerState::HalfOpen;
+        }
+        true
+    }
+
+    fn record(&self, succeeded: bool, now: Instant) {
+        let mut state = self.lock_state();
+        *state = match (*state, succeeded) {
+            (_, true) => BreakerState::Closed {
+                consecutive_failures: 0,
+            },
+            (
+                BreakerState::Closed {
+                    consecutive_failures,
+                },
+                false,
+            ) if consecutive_failures + 1 < self.failure_threshold => BreakerState::Closed {
+                consecutive_failures: consecutive_failures + 1,
+            },
+            (_, false) => BreakerState::Open { opened_at: now },
+        };
+    }
+
+    async fn call<Fut>(&self, now: impl Fn() -> Instant, run: Fut) -> Result<(), TimerError>
+    where
+        Fut: Future<Output = Result<(), TimerError>>,
+    {
+        if !self.admit(now()) {
+            return Ok(());
+        }
+
+        let result = run.await;
+        self.record(result.is_ok(), now());
+        result
+    }
+}
+
+#[async_trait]
+impl<C> TimerCallback for CircuitBreaker<C>
+where
+    C: TimerCallback,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        self.call(Instant::now, self.callback.execute()).await
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        let runtime = context.timer().inner.runtime.clone();
+        self.call(
+            || runtime.now(),
+            self.callback.execute_with_context(context),
+        )
+        .await
+    }
+}
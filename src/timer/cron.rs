@@ -0,0 +1,213 @@
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::errors::TimerError;
+
+const MINUTES_PER_DAY: u64 = 24 * 60;
+/// How far ahead to look for a match; February 29th can be eight years apart.
+const SEARCH_MINUTES: u64 = 9 * 366 * MINUTES_PER_DAY;
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A parsed five-field cron expression, evaluated in UTC.
+///
+/// The fields are minute, hour, day of month, month, and day of week. Each
+/// accepts `*`, single values, ranges (`1-5`), steps (`*/15`, `0-30/10`), and
+/// comma-separated lists of those. Months and days of week also accept
+/// three-letter English names, and day of week `7` means Sunday. As in
+/// standard cron, when both day fields are restricted a day matching either
+/// one fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    any_day_of_month: bool,
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    /// Parses a five-field cron expression.
+    ///
+    /// Errors are [`TimerError::invalid_parameter`] values whose message
+    /// names the offending field.
+    pub fn parse(expression: &str) -> Result<Self, TimerError> {
+        let invalid = |reason: String| {
+            TimerError::invalid_parameter(format!(
+                "Invalid cron expression '{expression}': {reason}"
+            ))
+        };
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            return Err(invalid(format!(
+                "expected 5 fields, found {}",
+                fields.len()
+            )));
+        };
+
+        let mut days_of_week_bits =
+            parse_field(days_of_week, 0, 7, &DAY_NAMES, 0).map_err(&invalid)?;
+        if days_of_week_bits & (1 << 7) != 0 {
+            days_of_week_bits = (days_of_week_bits & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minutes, 0, 59, &[], 0).map_err(&invalid)?,
+            hours: parse_field(hours, 0, 23, &[], 0).map_err(&invalid)?,
+            days_of_month: parse_field(days_of_month, 1, 31, &[], 0).map_err(&invalid)?,
+            months: parse_field(months, 1, 12, &MONTH_NAMES, 1).map_err(&invalid)?,
+            days_of_week: days_of_week_bits,
+            any_day_of_month: days_of_month.starts_with('*'),
+            any_day_of_week: days_of_week.starts_with('*'),
+        })
+    }
+
+    /// Returns the first matching minute strictly after `time`.
+    ///
+    /// Returns `None` when the expression never matches, such as for
+    /// February 30th.
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let mut minute = since_epoch.as_secs() / 60 + 1;
+        let limit = minute + SEARCH_MINUTES;
+
+        while minute < limit {
+            let day = minute / MINUTES_PER_DAY;
+            if !self.matches_day(day) {
+                minute = (day + 1) * MINUTES_PER_DAY;
+                continue;
+            }
+            if !contains(self.hours, (minute % MINUTES_PER_DAY) / 60) {
+                minute = (minute / 60 + 1) * 60;
+                continue;
+            }
+            if !contains(self.minutes, minute % 60) {
+                minute += 1;
+                continue;
+            }
+            return Some(UNIX_EPOCH + Duration::from_secs(minute * 60));
+        }
+        None
+    }
+
+    fn matches_day(&self, days_since_epoch: u64) -> bool {
+        let (month, day_of_month) = month_and_day(days_since_epoch);
+        if !contains(self.months, month) {
+            return false;
+        }
+
+        // The epoch fell on a Thursday.
+        let day_of_week = (days_since_epoch + 4) % 7;
+        let month_day_matches = contains(self.days_of_month, day_of_month);
+        let week_day_matches = contains(self.days_of_week, day_of_week);
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (false, false) => month_day_matches || week_day_matches,
+            (true, false) => week_day_matches,
+            (false, true) | (true, true) => month_day_matches,
+        }
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = TimerError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        Self::parse(expression)
+    }
+}
+
+fn contains(bits: u64, value: u64) -> bool {
+    bits & (1 << value) != 0
+}
+
+/// Parses one field into a bit set of its allowed values.
+fn parse_field(
+    field: &str,
+    min: u64,
+    max: u64,
+    names: &[&str],
+    first_name_value: u64,
+) -> Result<u64, String> {
+    let mut bits = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step: u64 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step '{step}' in '{field}'"))?;
+                if step == 0 {
+                    return Err(format!("step must be greater than zero in '{field}'"));
+                }
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+
+        let value = |text: &str| parse_value(text, min, max, names, first_name_value, field);
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else {
+            match range.split_once('-') {
+                Some((start, end)) => (value(start)?, value(end)?),
+                None if step.is_some() => (value(range)?, max),
+                None => {
+                    let single = value(range)?;
+                    (single, single)
+                }
+            }
+        };
+        if start > end {
+            return Err(format!("range '{range}' is reversed in '{field}'"));
+        }
+
+        let step = usize::try_from(step.unwrap_or(1)).unwrap_or(usize::MAX);
+        for allowed in (start..=end).step_by(step) {
+            bits |= 1 << allowed;
+        }
+    }
+    Ok(bits)
+}
+
+fn parse_value(
+    text: &str,
+    min: u64,
+    max: u64,
+    names: &[&str],
+    first_name_value: u64,
+    field: &str,
+) -> Result<u64, String> {
+    let value = match names
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(text))
+    {
+        Some(index) => index as u64 + first_name_value,
+        None => text
+            .parse()
+            .map_err(|_| format!("invalid value '{text}' in '{field}'"))?,
+    };
+    if !(min..=max).contains(&value) {
+        return Err(format!("value {value} in '{field}' is outside {min}-{max}"));
+    }
+    Ok(value)
+}
+
+/// Converts days since the Unix epoch to a proleptic Gregorian month and day.
+fn month_and_day(days_since_epoch: u64) -> (u64, u64) {
+    let shifted = days_since_epoch + 719_468;
+    let day_of_era = shifted % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    (month, day)
+}
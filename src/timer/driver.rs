@@ -1,12 +1,12 @@
 use std::future::Future;
 use std::pin::Pin;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use tokio::task::JoinHandle;
 use tokio::time::{self, Instant};
 
 #[cfg(feature = "test-util")]
-use std::collections::VecDeque;
+use std::collections::{BTreeSet, VecDeque};
 #[cfg(feature = "test-util")]
 use std::sync::{Arc, Mutex};
 #[cfg(feature = "test-util")]
@@ -24,7 +24,7 @@ pub(crate) enum RuntimeHandle {
 }
 
 impl RuntimeHandle {
-    pub(super) fn now(&self) -> Instant {
+    pub(crate) fn now(&self) -> Instant {
         match self {
             Self::Native => Instant::now(),
 
@@ -33,11 +33,20 @@ impl RuntimeHandle {
         }
     }
 
+    pub(super) fn system_now(&self) -> SystemTime {
+        match self {
+            Self::Native => SystemTime::now(),
+
+            #[cfg(feature = "test-util")]
+            Self::Mock(inner) => inner.system_now(),
+        }
+    }
+
     pub(super) fn sleep(&self, duration: Duration) -> SleepFuture {
         self.sleep_until(self.now() + duration)
     }
 
-    pub(super) fn sleep_until(&self, deadline: Instant) -> SleepFuture {
+    pub(crate) fn sleep_until(&self, deadline: Instant) -> SleepFuture {
         match self {
             Self::Native => Box::pin(time::sleep_until(deadline)),
 
@@ -93,7 +102,9 @@ impl MockRuntime {
         Self {
             inner: Arc::new(MockRuntimeInner {
                 now: Mutex::new(now),
+                system_anchor: Mutex::new((now, SystemTime::now())),
                 time_tx,
+                deadlines: Mutex::new(BTreeSet::new()),
                 jitter_samples: Mutex::new(VecDeque::new()),
             }),
         }
@@ -109,6 +120,18 @@ impl MockRuntime {
         self.settle().await;
     }
 
+    /// Advances the clock to every pending sleep deadline in order, then to `duration`.
+    pub(crate) async fn advance_through(&self, duration: Duration) {
+        let target = self.now() + duration;
+        self.settle().await;
+        while let Some(deadline) = self.inner.next_deadline_until(target) {
+            self.advance(deadline.saturating_duration_since(self.now()))
+                .await;
+        }
+        self.advance(target.saturating_duration_since(self.now()))
+            .await;
+    }
+
     /// Yields long enough for spawned timer tasks to observe the latest clock state.
     pub async fn settle(&self) {
         for _ in 0..8 {
@@ -125,12 +148,26 @@ impl MockRuntime {
     pub fn now(&self) -> Instant {
         self.inner.now()
     }
+
+    /// Pins the mocked wall clock to the provided time at the current mocked instant.
+    ///
+    /// The wall clock advances together with [`MockRuntime::advance`].
+    pub fn set_system_time(&self, time: SystemTime) {
+        self.inner.set_system_time(time);
+    }
+
+    /// Returns the mocked wall-clock time.
+    pub fn system_now(&self) -> SystemTime {
+        self.inner.system_now()
+    }
 }
 
 #[cfg(feature = "test-util")]
 pub(crate) struct MockRuntimeInner {
     now: Mutex<Instant>,
+    system_anchor: Mutex<(Instant, SystemTime)>,
     time_tx: watch::Sender<Instant>,
+    deadlines: Mutex<BTreeSet<Instant>>,
     jitter_samples: Mutex<VecDeque<Duration>>,
 }
 
@@ -140,6 +177,21 @@ impl MockRuntimeInner {
         *self.now.lock().expect("mock runtime now lock poisoned")
     }
 
+    fn system_now(&self) -> SystemTime {
+        let (anchor, system) = *self
+            .system_anchor
+            .lock()
+            .expect("mock runtime system clock lock poisoned");
+        system + self.now().saturating_duration_since(anchor)
+    }
+
+    fn set_system_time(&self, time: SystemTime) {
+        *self
+            .system_anchor
+            .lock()
+            .expect("mock runtime system clock lock poisoned") = (self.now(), time);
+    }
+
     fn advance(&self, duration: Duration) {
         let mut now = self.now.lock().expect("mock runtime now lock poisoned");
         *now += duration;
@@ -162,7 +214,27 @@ impl MockRuntimeInner {
             .min(max_jitter)
     }
 
+    fn next_deadline_until(&self, target: Instant) -> Option<Instant> {
+        let now = self.now();
+        let mut deadlines = self
+            .deadlines
+            .lock()
+            .expect("mock runtime deadlines lock poisoned");
+        *deadlines = deadlines.split_off(&now);
+        deadlines.remove(&now);
+        deadlines
+            .first()
+            .copied()
+            .filter(|deadline| *deadline <= target)
+    }
+
     fn sleep_until(&self, deadline: Instant) -> SleepFuture {
+        if deadline > self.now() {
+            self.deadlines
+                .lock()
+                .expect("mock runtime deadlines lock poisoned")
+                .insert(deadline);
+        }
         let mut rx = self.time_tx.subscribe();
         Box::pin(async move {
             loop {
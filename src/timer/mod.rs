@@ -1,30 +1,56 @@
 use async_trait::async_trait;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::future::Future;
 use std::sync::{
-    atomic::{AtomicBool, AtomicU64, Ordering},
-    Arc,
+    atomic::{AtomicBool, AtomicU64, AtomicU8, AtomicUsize, Ordering},
+    Arc, Weak,
 };
-use std::time::Duration;
-use tokio::sync::{broadcast, mpsc, watch, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{broadcast, mpsc, watch, Mutex, Notify};
 use tokio::task::JoinHandle;
 use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
 
 #[cfg(feature = "logging")]
 use log::debug;
 
 use crate::errors::TimerError;
+use crate::registry::{RegistryInner, SharedRng, TimerRegistry};
 
+mod circuit_breaker;
+mod cron;
 pub(crate) mod driver;
+mod profiling;
 mod runtime;
+#[cfg(feature = "test-util")]
+mod test_scheduler;
 
 #[cfg(test)]
 mod tests;
 
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+pub use cron::CronSchedule;
 #[cfg(feature = "test-util")]
 pub use driver::MockRuntime;
+#[cfg(feature = "profiling")]
+pub use profiling::LockProfile;
+use profiling::{LockSite, Profiled};
+#[cfg(feature = "test-util")]
+pub use test_scheduler::TestScheduler;
 
 const TIMER_EVENT_BUFFER: usize = 64;
+const EVENTS_PER_TICK: usize = 2;
+const SECONDS_PER_DAY: u32 = 86_400;
+const INTER_ARRIVAL_CAPACITY: usize = 64;
+const DRIFT_BUCKET_BOUNDS: [Duration; 7] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+];
 
 fn saturating_mul_duration(duration: Duration, multiplier: u32) -> Duration {
     let nanos = duration.as_nanos();
@@ -35,10 +61,41 @@ fn saturating_mul_duration(duration: Duration, multiplier: u32) -> Duration {
 
 /// Represents the state of a timer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum TimerState {
     Running,
     Paused,
+    /// The timer has not started, or its last run was stopped, cancelled, or replaced.
     Stopped,
+    /// The last run finished on its own: a one-shot timer fired or a
+    /// recurring timer reached its expiration count.
+    Completed,
+}
+
+impl TimerState {
+    /// Returns true while a run is in progress, whether running or paused.
+    pub fn is_active(self) -> bool {
+        matches!(self, Self::Running | Self::Paused)
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Running => 0,
+            Self::Paused => 1,
+            Self::Stopped => 2,
+            Self::Completed => 3,
+        }
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Running),
+            1 => Some(Self::Paused),
+            2 => Some(Self::Stopped),
+            3 => Some(Self::Completed),
+            _ => None,
+        }
+    }
 }
 
 /// Indicates how a timer run ended.
@@ -50,21 +107,205 @@ pub enum TimerFinishReason {
     Replaced,
 }
 
+/// Indicates whether a one-shot run executed its callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OneShotOutcome {
+    /// The run is still armed and has not executed its callback yet.
+    Pending,
+    /// The callback executed.
+    Fired,
+    /// The run ended before the callback executed.
+    Cancelled,
+}
+
 /// Statistics for a timer run.
+///
+/// `execution_count` counts ticks that ran the callback, while
+/// `successful_executions` and `failed_executions` count individual attempts,
+/// so with a retry policy a single tick can add several failures. Failures
+/// are recorded whether or not the `logging` feature is enabled.
+///
+/// With the `serde` feature the statistics serialize to a flat map, with
+/// every duration encoded as whole milliseconds. Missing fields deserialize
+/// to their defaults, so maps written before a counter existed still load.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+#[non_exhaustive]
 pub struct TimerStatistics {
     /// Number of callback executions attempted during the current run.
     pub execution_count: usize,
-    /// Number of successful callback executions.
+    /// Number of callback attempts that succeeded.
     pub successful_executions: usize,
-    /// Number of failed callback executions.
+    /// Number of callback attempts that failed, including failed retries.
     pub failed_executions: usize,
-    /// Total elapsed time since the current run started.
+    /// Number of retry attempts made after a failed callback attempt.
+    pub retries: usize,
+    /// Number of scheduled ticks skipped because they fell outside the active
+    /// window or the timer's enabled flag was off.
+    pub skipped_executions: usize,
+    /// Number of fixed-rate ticks dropped because the previous callback overran them.
+    pub missed_executions: usize,
+    /// Number of scheduled ticks skipped because the timer was not the leader.
+    pub follower_skips: usize,
+    /// Number of fixed-rate executions that started later than the schedule's tolerance.
+    pub late_executions: usize,
+    /// Number of callback attempts that finished after exceeding the soft timeout.
+    pub soft_timeouts: usize,
+    /// Number of callback attempts cancelled by the hard callback timeout.
+    pub hard_timeouts: usize,
+    /// Shortest execution observed, including retries. Zero before the first execution.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))]
+    pub min_execution_duration: Duration,
+    /// Longest execution observed, including retries.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))]
+    pub max_execution_duration: Duration,
+    /// Total time spent executing callbacks, including retries.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))]
+    pub total_execution_duration: Duration,
+    /// Time the current run has spent active since it started, excluding pauses.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))]
     pub elapsed_time: Duration,
+    /// Time between consecutive executions, oldest first.
+    ///
+    /// Only the 64 most recent deltas are kept.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration::vec"))]
+    pub inter_arrival_times: Vec<Duration>,
     /// The most recent callback error observed in the current run.
     pub last_error: Option<TimerError>,
 }
 
+impl TimerStatistics {
+    /// Returns the average execution duration, or zero before the first execution.
+    pub fn average_execution_duration(&self) -> Duration {
+        match u32::try_from(self.execution_count) {
+            Ok(0) => Duration::ZERO,
+            Ok(count) => self.total_execution_duration / count,
+            Err(_) => Duration::from_secs_f64(
+                self.total_execution_duration.as_secs_f64() / self.execution_count as f64,
+            ),
+        }
+    }
+
+    fn merge(&mut self, tick: &TimerStatistics) {
+        if tick.execution_count > 0 {
+            self.min_execution_duration = if self.execution_count == 0 {
+                tick.min_execution_duration
+            } else {
+                self.min_execution_duration.min(tick.min_execution_duration)
+            };
+        }
+        self.execution_count += tick.execution_count;
+        self.successful_executions += tick.successful_executions;
+        self.failed_executions += tick.failed_executions;
+        self.retries += tick.retries;
+        self.skipped_executions += tick.skipped_executions;
+        self.missed_executions += tick.missed_executions;
+        self.follower_skips += tick.follower_skips;
+        self.late_executions += tick.late_executions;
+        self.soft_timeouts += tick.soft_timeouts;
+        self.hard_timeouts += tick.hard_timeouts;
+        self.max_execution_duration = self.max_execution_duration.max(tick.max_execution_duration);
+        self.total_execution_duration = self
+            .total_execution_duration
+            .saturating_add(tick.total_execution_duration);
+        if tick.last_error.is_some() {
+            self.last_error = tick.last_error.clone();
+        }
+        self.inter_arrival_times
+            .extend_from_slice(&tick.inter_arrival_times);
+        let overflow = self
+            .inter_arrival_times
+            .len()
+            .saturating_sub(INTER_ARRIVAL_CAPACITY);
+        self.inter_arrival_times.drain(..overflow);
+    }
+
+    fn record_execution_duration(&mut self, duration: Duration) {
+        self.min_execution_duration = if self.execution_count == 0 {
+            duration
+        } else {
+            self.min_execution_duration.min(duration)
+        };
+        self.max_execution_duration = self.max_execution_duration.max(duration);
+        self.total_execution_duration = self.total_execution_duration.saturating_add(duration);
+    }
+}
+
+/// Opaque point-in-time capture of a timer's statistics, created by [`Timer::checkpoint`].
+#[derive(Debug, Clone)]
+pub struct StatisticsCheckpoint {
+    statistics_epoch: u64,
+    statistics: TimerStatistics,
+    taken_at: Instant,
+}
+
+/// Statistics accumulated between a [`StatisticsCheckpoint`] and now.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StatisticsDelta {
+    /// Number of callback executions attempted since the checkpoint.
+    pub execution_count: usize,
+    /// Number of successful callback executions since the checkpoint.
+    pub successful_executions: usize,
+    /// Number of failed callback executions since the checkpoint.
+    pub failed_executions: usize,
+    /// Number of scheduled ticks skipped outside the active window since the checkpoint.
+    pub skipped_executions: usize,
+    /// Time spent executing callbacks since the checkpoint, including retries.
+    pub total_execution_duration: Duration,
+    /// Time elapsed since the checkpoint was taken.
+    pub elapsed: Duration,
+}
+
+/// Distribution of scheduling drift, the delay between a tick's deadline and its execution.
+///
+/// Samples are counted in fixed buckets whose exclusive upper bounds are 1ms,
+/// 5ms, 10ms, 50ms, 100ms, 500ms, and 1s, followed by an unbounded bucket.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriftHistogram {
+    counts: [u64; DRIFT_BUCKET_BOUNDS.len() + 1],
+    max: Duration,
+}
+
+impl DriftHistogram {
+    /// Returns the total number of recorded samples.
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the largest drift recorded.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Returns each bucket's exclusive upper bound and sample count.
+    ///
+    /// The final bucket has no upper bound.
+    pub fn buckets(&self) -> Vec<(Option<Duration>, u64)> {
+        DRIFT_BUCKET_BOUNDS
+            .iter()
+            .copied()
+            .map(Some)
+            .chain(std::iter::once(None))
+            .zip(self.counts.iter().copied())
+            .collect()
+    }
+
+    fn record(&mut self, drift: Duration) {
+        let bucket = DRIFT_BUCKET_BOUNDS
+            .iter()
+            .position(|bound| drift < *bound)
+            .unwrap_or(DRIFT_BUCKET_BOUNDS.len());
+        self.counts[bucket] += 1;
+        self.max = self.max.max(drift);
+    }
+}
+
 /// Describes the result of a completed timer run.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TimerOutcome {
@@ -76,6 +317,22 @@ pub struct TimerOutcome {
     pub statistics: TimerStatistics,
 }
 
+/// A single callback execution recorded in a timer's execution history.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExecutionRecord {
+    /// The run that produced the execution.
+    pub run_id: u64,
+    /// The one-based execution number within the run.
+    pub execution: usize,
+    /// When the execution started, including any retries.
+    pub started_at: Instant,
+    /// How long the execution took, including any retries.
+    pub duration: Duration,
+    /// Whether the execution eventually succeeded.
+    pub succeeded: bool,
+}
+
 /// Metadata attached to a timer for observability.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct TimerMetadata {
@@ -85,8 +342,53 @@ pub struct TimerMetadata {
     pub tags: BTreeMap<String, String>,
 }
 
+/// Persistable description of a timer's schedule.
+///
+/// Callbacks cannot be serialized, so pair a config with a callback through
+/// [`Timer::from_config`] to recreate the timer. With the `serde` feature the
+/// interval is encoded as whole milliseconds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimerConfig {
+    /// Delay of a one-shot timer, or period of a recurring one.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_duration"))]
+    pub interval: Duration,
+    /// Whether the timer repeats.
+    pub recurring: bool,
+    /// Optional execution limit; only valid for recurring timers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expiration_count: Option<usize>,
+    /// Optional label for the timer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: Option<String>,
+}
+
+impl TimerConfig {
+    /// Builds the run configuration, labelling `metadata` with the name if set.
+    fn run_config(&self, mut metadata: TimerMetadata) -> Result<RunConfig, TimerError> {
+        if let Some(name) = &self.name {
+            metadata.label = Some(name.clone());
+        }
+
+        if self.recurring {
+            let mut schedule = RecurringSchedule::new(self.interval);
+            if let Some(expiration_count) = self.expiration_count {
+                schedule = schedule.with_expiration_count(expiration_count);
+            }
+            Ok(RunConfig::recurring(schedule, metadata))
+        } else if self.expiration_count.is_some() {
+            Err(TimerError::invalid_parameter(
+                "Expiration count only applies to recurring timers.",
+            ))
+        } else {
+            Ok(RunConfig::once(self.interval, metadata))
+        }
+    }
+}
+
 /// Snapshot of the current or most recent timer state.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct TimerSnapshot {
     /// The timer state observed for the snapshot.
     pub state: TimerState,
@@ -100,15 +402,211 @@ pub struct TimerSnapshot {
     pub last_outcome: Option<TimerOutcome>,
     /// Metadata associated with the timer.
     pub metadata: TimerMetadata,
+    /// When the timer was created.
+    pub created_at: Instant,
 }
 
 /// Defines how recurring timers schedule the next execution.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RecurringCadence {
+    /// Wait a full interval after each execution finishes, so callback time
+    /// accumulates into the period.
     FixedDelay,
+    /// Fire at `start + n * interval` regardless of how long callbacks take,
+    /// so the period does not drift. Combine with [`OverlapPolicy::Skip`] to
+    /// coalesce ticks missed by an overrunning callback.
     FixedRate,
 }
 
+/// Controls how much per-run statistics a timer records.
+///
+/// Lower levels trade observability for less allocation and locking on every
+/// tick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatsLevel {
+    /// Record no statistics, drift samples, execution history, or last result.
+    Off,
+    /// Record only execution, success, failure, and skip counts in atomics.
+    Counters,
+    /// Record all statistics.
+    #[default]
+    Full,
+}
+
+/// Defines what a fixed-rate timer does with ticks that came due while its
+/// callback was still running.
+///
+/// Callbacks never run concurrently; fixed-delay timers measure the interval
+/// from the end of the previous execution, so they never overlap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverlapPolicy {
+    /// Run overdue ticks back to back once the callback finishes.
+    #[default]
+    Queue,
+    /// Drop overdue ticks and wait for the next deadline that has not passed.
+    ///
+    /// Dropped ticks are counted in [`TimerStatistics::missed_executions`].
+    Skip,
+}
+
+impl StatsLevel {
+    fn to_u8(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::Counters => 1,
+            Self::Full => 2,
+        }
+    }
+
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::Off,
+            1 => Self::Counters,
+            _ => Self::Full,
+        }
+    }
+}
+
+/// Lock-free execution counters used by [`StatsLevel::Counters`].
+#[derive(Default)]
+pub(super) struct ExecutionCounters {
+    executions: AtomicUsize,
+    successes: AtomicUsize,
+    failures: AtomicUsize,
+    skipped: AtomicUsize,
+}
+
+impl ExecutionCounters {
+    pub(super) fn record(&self, tick: &TimerStatistics) {
+        self.executions
+            .fetch_add(tick.execution_count, Ordering::Relaxed);
+        self.successes
+            .fetch_add(tick.successful_executions, Ordering::Relaxed);
+        self.failures
+            .fetch_add(tick.failed_executions, Ordering::Relaxed);
+        self.skipped
+            .fetch_add(tick.skipped_executions, Ordering::Relaxed);
+    }
+
+    pub(super) fn snapshot(&self) -> TimerStatistics {
+        TimerStatistics {
+            execution_count: self.executions.load(Ordering::Relaxed),
+            successful_executions: self.successes.load(Ordering::Relaxed),
+            failed_executions: self.failures.load(Ordering::Relaxed),
+            skipped_executions: self.skipped.load(Ordering::Relaxed),
+            ..TimerStatistics::default()
+        }
+    }
+
+    fn take(&self) -> TimerStatistics {
+        TimerStatistics {
+            execution_count: self.executions.swap(0, Ordering::Relaxed),
+            successful_executions: self.successes.swap(0, Ordering::Relaxed),
+            failed_executions: self.failures.swap(0, Ordering::Relaxed),
+            skipped_executions: self.skipped.swap(0, Ordering::Relaxed),
+            ..TimerStatistics::default()
+        }
+    }
+}
+
+/// Defines how a timer reacts when its callback panics.
+///
+/// A panic is always caught and recorded as a failed execution with a
+/// [`TimerError::callback_panicked`] error; the policy decides what happens to
+/// the run afterwards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PanicPolicy {
+    /// Restart the run's schedule from the beginning, as if it had just started.
+    ///
+    /// The run keeps its identifier and statistics, so `execution_count`
+    /// spans restarts and can exceed the expiration count, which starts over
+    /// with each restarted schedule. One-shot runs are not re-armed; they
+    /// complete as under [`PanicPolicy::Ignore`].
+    Restart,
+    /// Stop the run with [`TimerFinishReason::Stopped`].
+    #[default]
+    Stop,
+    /// Keep running as if the execution had failed normally.
+    Ignore,
+}
+
+/// Defines how a recurring timer handles intervals missed while it was paused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResumeCatchUp {
+    /// Resume without firing for missed intervals.
+    ///
+    /// The next tick fires once the time that was left in the interrupted
+    /// interval has elapsed, or a full interval later if the interval was
+    /// adjusted while paused.
+    #[default]
+    ContinueOnSchedule,
+    /// Fire once immediately if at least one interval was missed.
+    FireOnce,
+    /// Fire once per missed interval, up to the provided bound, immediately after resuming.
+    FireMissed(usize),
+}
+
+impl ResumeCatchUp {
+    pub(super) fn catch_up_ticks(self, missed: usize) -> usize {
+        match self {
+            Self::ContinueOnSchedule => 0,
+            Self::FireOnce => missed.min(1),
+            Self::FireMissed(max) => missed.min(max),
+        }
+    }
+}
+
+/// A UTC time of day used to gate recurring executions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeOfDay {
+    seconds: u32,
+}
+
+impl TimeOfDay {
+    /// Midnight, the start of the day.
+    pub const MIDNIGHT: Self = Self { seconds: 0 };
+
+    /// Creates a time of day from hours, minutes, and seconds.
+    pub fn from_hms(hour: u32, minute: u32, second: u32) -> Result<Self, TimerError> {
+        if hour >= 24 || minute >= 60 || second >= 60 {
+            return Err(TimerError::invalid_parameter(
+                "Time of day must be within 00:00:00 and 23:59:59.",
+            ));
+        }
+
+        Ok(Self {
+            seconds: hour * 3_600 + minute * 60 + second,
+        })
+    }
+
+    /// Returns the hour component.
+    pub fn hour(self) -> u32 {
+        self.seconds / 3_600
+    }
+
+    /// Returns the minute component.
+    pub fn minute(self) -> u32 {
+        self.seconds % 3_600 / 60
+    }
+
+    /// Returns the second component.
+    pub fn second(self) -> u32 {
+        self.seconds % 60
+    }
+
+    /// Returns the UTC time of day for a wall-clock time.
+    pub fn from_system_time(time: SystemTime) -> Self {
+        let since_epoch = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        Self {
+            seconds: (since_epoch.as_secs() % u64::from(SECONDS_PER_DAY)) as u32,
+        }
+    }
+}
+
 /// Configures the schedule for a recurring timer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct RecurringSchedule {
@@ -117,6 +615,11 @@ pub struct RecurringSchedule {
     cadence: RecurringCadence,
     expiration_count: Option<usize>,
     jitter: Option<Duration>,
+    active_window: Option<(TimeOfDay, TimeOfDay)>,
+    resume_catch_up: ResumeCatchUp,
+    tolerance: Duration,
+    count_errors_toward_limit: bool,
+    immediate_first_tick: bool,
 }
 
 impl RecurringSchedule {
@@ -128,6 +631,11 @@ impl RecurringSchedule {
             cadence: RecurringCadence::FixedDelay,
             expiration_count: None,
             jitter: None,
+            active_window: None,
+            resume_catch_up: ResumeCatchUp::ContinueOnSchedule,
+            tolerance: Duration::ZERO,
+            count_errors_toward_limit: true,
+            immediate_first_tick: false,
         }
     }
 
@@ -156,12 +664,46 @@ impl RecurringSchedule {
         self.jitter
     }
 
+    /// Returns the optional daily window outside of which ticks are skipped.
+    pub fn active_window(self) -> Option<(TimeOfDay, TimeOfDay)> {
+        self.active_window
+    }
+
+    /// Returns the policy for intervals missed while the timer was paused.
+    pub fn resume_catch_up(self) -> ResumeCatchUp {
+        self.resume_catch_up
+    }
+
+    /// Returns how late a fixed-rate tick may start before it counts as late.
+    pub fn tolerance(self) -> Duration {
+        self.tolerance
+    }
+
+    /// Returns whether failed executions count toward the expiration count.
+    pub fn counts_errors_toward_limit(self) -> bool {
+        self.count_errors_toward_limit
+    }
+
+    /// Returns whether the first execution happens as soon as the timer starts.
+    pub fn fires_immediately(self) -> bool {
+        self.immediate_first_tick
+    }
+
     /// Sets an initial delay before the first recurring execution.
     pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
         self.initial_delay = Some(initial_delay);
         self
     }
 
+    /// Executes the first tick as soon as the timer starts instead of after one interval.
+    ///
+    /// The immediate execution counts as the first tick toward the
+    /// expiration count. Cannot be combined with an initial delay.
+    pub fn with_immediate_first_tick(mut self) -> Self {
+        self.immediate_first_tick = true;
+        self
+    }
+
     /// Sets the cadence used for subsequent executions.
     pub fn with_cadence(mut self, cadence: RecurringCadence) -> Self {
         self.cadence = cadence;
@@ -174,23 +716,69 @@ impl RecurringSchedule {
         self
     }
 
-    /// Uses fixed-rate cadence semantics.
+    /// Uses fixed-rate cadence semantics, correcting for callback drift.
     pub fn fixed_rate(mut self) -> Self {
         self.cadence = RecurringCadence::FixedRate;
         self
     }
 
+    /// Treats fixed-rate ticks that start within `tolerance` of their deadline as on time.
+    ///
+    /// Ticks that start later are counted in [`TimerStatistics::late_executions`].
+    pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets how intervals missed during a pause are handled on resume.
+    pub fn with_resume_catch_up(mut self, resume_catch_up: ResumeCatchUp) -> Self {
+        self.resume_catch_up = resume_catch_up;
+        self
+    }
+
     /// Limits the number of recurring executions.
+    ///
+    /// The run completes right after its `expiration_count`-th execution.
+    /// A count of zero is rejected with [`TimerError::InvalidParameter`] when
+    /// the timer starts, since a run that never executes is better expressed
+    /// by not starting it.
     pub fn with_expiration_count(mut self, expiration_count: usize) -> Self {
         self.expiration_count = Some(expiration_count);
         self
     }
 
+    /// Sets whether failed executions count toward the expiration count.
+    ///
+    /// Enabled by default. When disabled, only successful executions count,
+    /// so an expiration count of five means five successful runs.
+    pub fn with_count_errors_toward_limit(mut self, count_errors_toward_limit: bool) -> Self {
+        self.count_errors_toward_limit = count_errors_toward_limit;
+        self
+    }
+
     /// Adds bounded jitter to recurring delays.
     pub fn with_jitter(mut self, jitter: Duration) -> Self {
         self.jitter = Some(jitter);
         self
     }
+
+    /// Only executes ticks that fall within `[start, end)` of the UTC day.
+    ///
+    /// Windows where `end` precedes `start` wrap around midnight. Ticks outside
+    /// the window keep the schedule advancing and are counted as skipped.
+    pub fn with_active_window(mut self, start: TimeOfDay, end: TimeOfDay) -> Self {
+        self.active_window = Some((start, end));
+        self
+    }
+}
+
+fn window_contains(window: (TimeOfDay, TimeOfDay), time: TimeOfDay) -> bool {
+    let (start, end) = window;
+    if start <= end {
+        start <= time && time < end
+    } else {
+        time >= start || time < end
+    }
 }
 
 /// Configures retry behavior for failed callback executions.
@@ -287,17 +875,45 @@ pub enum TimerEvent {
 }
 
 /// Subscription handle for timer events.
+///
+/// Events are delivered in order. A subscriber that falls more than the
+/// timer's event capacity behind skips the oldest events; the number skipped
+/// is reported by [`TimerEvents::lagged_events`].
 pub struct TimerEvents {
     receiver: broadcast::Receiver<TimerEvent>,
+    lagged: u64,
+    // Declared after `receiver` so it signals once the receiver's slots are freed.
+    drained: DrainSignal,
+}
+
+/// Wakes ticks held back by [`TimerBuilder::pause_on_full_events`] when a
+/// subscriber consumes events or goes away.
+struct DrainSignal(Arc<Notify>);
+
+impl Drop for DrainSignal {
+    fn drop(&mut self) {
+        self.0.notify_waiters();
+    }
 }
 
 impl TimerEvents {
+    /// Returns how many events this subscriber missed by falling behind.
+    pub fn lagged_events(&self) -> u64 {
+        self.lagged
+    }
+
     /// Attempts to receive the next timer event without waiting.
     pub fn try_recv(&mut self) -> Option<TimerEvent> {
         loop {
             match self.receiver.try_recv() {
-                Ok(event) => return Some(event),
-                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Ok(event) => {
+                    self.drained.0.notify_waiters();
+                    return Some(event);
+                }
+                Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                    self.lagged += skipped;
+                    self.drained.0.notify_waiters();
+                }
                 Err(broadcast::error::TryRecvError::Empty)
                 | Err(broadcast::error::TryRecvError::Closed) => return None,
             }
@@ -308,8 +924,14 @@ impl TimerEvents {
     pub async fn recv(&mut self) -> Option<TimerEvent> {
         loop {
             match self.receiver.recv().await {
-                Ok(event) => return Some(event),
-                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Ok(event) => {
+                    self.drained.0.notify_waiters();
+                    return Some(event);
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    self.lagged += skipped;
+                    self.drained.0.notify_waiters();
+                }
                 Err(broadcast::error::RecvError::Closed) => return None,
             }
         }
@@ -421,118 +1043,777 @@ impl TimerCompletion {
 pub trait TimerCallback: Send + Sync {
     /// The function to execute when the timer triggers.
     async fn execute(&self) -> Result<(), TimerError>;
+
+    /// Executes the callback with access to the running timer and its registry.
+    ///
+    /// Timer executions call this method; the default forwards to
+    /// [`TimerCallback::execute`].
+    async fn execute_with_context(&self, _context: TimerContext) -> Result<(), TimerError> {
+        self.execute().await
+    }
+}
+
+/// The error returned when a callback that needs a [`TimerContext`] is
+/// executed without one.
+fn context_required() -> TimerError {
+    TimerError::callback_failed("This callback needs a timer context to execute.")
 }
 
+/// A callback shared by several runs or timers.
+pub(crate) struct SharedCallback(pub(crate) Arc<dyn TimerCallback>);
+
 #[async_trait]
-impl<F, Fut> TimerCallback for F
-where
-    F: Fn() -> Fut + Send + Sync,
-    Fut: Future<Output = Result<(), TimerError>> + Send,
-{
+impl TimerCallback for SharedCallback {
     async fn execute(&self) -> Result<(), TimerError> {
-        (self)().await
+        self.0.execute().await
     }
-}
 
-pub(super) enum TimerCommand {
-    Pause,
-    Resume,
-    Stop,
-    Cancel,
-    SetInterval(Duration),
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        self.0.execute_with_context(context).await
+    }
 }
 
-pub(super) struct TimerInner {
-    pub(super) state: Mutex<TimerState>,
-    pub(super) handle: Mutex<Option<JoinHandle<()>>>,
-    pub(super) command_tx: Mutex<Option<mpsc::UnboundedSender<TimerCommand>>>,
-    pub(super) interval: Mutex<Duration>,
-    pub(super) expiration_count: Mutex<Option<usize>>,
-    pub(super) metadata: Mutex<TimerMetadata>,
-    pub(super) statistics: Mutex<TimerStatistics>,
-    pub(super) last_outcome: Mutex<Option<TimerOutcome>>,
-    pub(super) completion_tx: watch::Sender<Option<TimerOutcome>>,
-    pub(super) event_tx: broadcast::Sender<TimerEvent>,
-    pub(super) events_enabled: AtomicBool,
-    pub(super) runtime: driver::RuntimeHandle,
-    pub(super) next_run_id: AtomicU64,
-    pub(super) active_run_id: AtomicU64,
+/// Execution context handed to [`TimerCallback::execute_with_context`].
+///
+/// The registry handle is only present for timers tracked by a
+/// [`TimerRegistry`]. Registry operations never hold the
+/// registry lock while awaiting a timer, so callbacks may safely control
+/// sibling timers through it. Two callbacks that synchronously stop each other
+/// can still wait on one another forever; prefer the `request_*` variants for
+/// that pattern.
+#[derive(Clone)]
+pub struct TimerContext {
+    run_id: u64,
+    execution: usize,
+    elapsed: Duration,
+    label: Option<String>,
+    timer: Timer,
+    registry: Option<TimerRegistry>,
+    cancellation: CancellationToken,
 }
 
-#[derive(Debug, Clone)]
-pub(super) struct RunConfig {
-    pub(super) interval: Duration,
-    pub(super) start_deadline: Option<Instant>,
-    pub(super) initial_delay: Option<Duration>,
-    pub(super) jitter: Option<Duration>,
-    pub(super) callback_timeout: Option<Duration>,
-    pub(super) retry_policy: Option<RetryPolicy>,
-    pub(super) recurring: bool,
-    pub(super) cadence: RecurringCadence,
-    pub(super) expiration_count: Option<usize>,
-    pub(super) metadata: TimerMetadata,
-}
+impl TimerContext {
+    /// Returns the identifier of the run executing the callback.
+    pub fn run_id(&self) -> u64 {
+        self.run_id
+    }
 
-#[derive(Debug, Clone, Copy)]
-enum TimerKind {
-    Once(Duration),
-    At(Instant),
-    Recurring(RecurringSchedule),
+    /// Returns the 1-based execution number within the run.
+    pub fn execution(&self) -> usize {
+        self.execution
+    }
+
+    /// Returns how long the run has been active when this attempt started,
+    /// excluding time spent paused.
+    pub fn elapsed_time(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the timer's label, if it has one.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns a handle to the timer executing the callback.
+    pub fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    /// Returns the registry tracking the timer, if any.
+    pub fn registry(&self) -> Option<&TimerRegistry> {
+        self.registry.as_ref()
+    }
+
+    /// Returns a token that is cancelled once the run is asked to stop or cancel, or finishes.
+    ///
+    /// Long-running callbacks can check or await it to exit cleanly at a safe
+    /// point instead of relying on the task being aborted.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Adds `value` to the run's running total for the custom metric `name`.
+    ///
+    /// Totals are summed across every callback attempt of the run and can be
+    /// read with [`Timer::custom_totals`].
+    pub fn record_metric(&self, name: impl Into<String>, value: f64) {
+        *self
+            .timer
+            .inner
+            .custom_totals
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(name.into())
+            .or_default() += value;
+    }
 }
 
-/// Builds and starts a timer with less boilerplate.
-pub struct TimerBuilder {
-    kind: TimerKind,
-    callback_timeout: Option<Duration>,
-    retry_policy: Option<RetryPolicy>,
-    start_paused: bool,
-    events_enabled: bool,
-    metadata: TimerMetadata,
+/// Adapts a closure that takes a [`TimerContext`] into a [`TimerCallback`].
+pub struct ContextCallback<F> {
+    callback: F,
 }
 
-#[derive(Clone)]
-/// Timer handle for managing one-time and recurring tasks.
-pub struct Timer {
-    inner: Arc<TimerInner>,
+impl<F> ContextCallback<F> {
+    /// Wraps a context-aware closure.
+    pub fn new(callback: F) -> Self {
+        Self { callback }
+    }
 }
 
-impl Default for Timer {
-    fn default() -> Self {
-        Self::new()
+#[async_trait]
+impl<F, Fut> TimerCallback for ContextCallback<F>
+where
+    F: Fn(TimerContext) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), TimerError>> + Send,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        Err(context_required())
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        (self.callback)(context).await
     }
 }
 
-impl Timer {
-    /// Creates a new timer.
+/// Runs several callbacks as one, in descending priority order.
+///
+/// Callbacks with equal priority run in the order they were added. Execution
+/// stops at the first callback that fails, so later callbacks can rely on
+/// state prepared by earlier ones.
+#[derive(Default)]
+pub struct CallbackGroup {
+    callbacks: Vec<(i32, Box<dyn TimerCallback>)>,
+}
+
+impl CallbackGroup {
+    /// Creates an empty callback group.
     pub fn new() -> Self {
-        Self::new_with_runtime(driver::RuntimeHandle::default(), true)
+        Self::default()
     }
 
-    /// Creates a new timer with broadcast events disabled.
-    pub fn new_silent() -> Self {
-        Self::new_with_runtime(driver::RuntimeHandle::default(), false)
+    /// Adds a callback with the default priority of `0`.
+    pub fn add_callback<C>(self, callback: C) -> Self
+    where
+        C: TimerCallback + 'static,
+    {
+        self.add_callback_with_priority(callback, 0)
     }
 
-    pub(crate) fn new_with_runtime(runtime: driver::RuntimeHandle, events_enabled: bool) -> Self {
-        let (completion_tx, _completion_rx) = watch::channel(None);
-        let (event_tx, _event_rx) = broadcast::channel(TIMER_EVENT_BUFFER);
+    /// Adds a callback that runs before every callback with a lower priority.
+    pub fn add_callback_with_priority<C>(mut self, callback: C, priority: i32) -> Self
+    where
+        C: TimerCallback + 'static,
+    {
+        let index = self
+            .callbacks
+            .partition_point(|(existing, _)| *existing >= priority);
+        self.callbacks.insert(index, (priority, Box::new(callback)));
+        self
+    }
+
+    /// Returns the number of callbacks in the group.
+    pub fn len(&self) -> usize {
+        self.callbacks.len()
+    }
+
+    /// Returns `true` when the group has no callbacks.
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+}
+
+#[async_trait]
+impl TimerCallback for CallbackGroup {
+    async fn execute(&self) -> Result<(), TimerError> {
+        for (_, callback) in &self.callbacks {
+            callback.execute().await?;
+        }
+        Ok(())
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        for (_, callback) in &self.callbacks {
+            callback.execute_with_context(context.clone()).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs `tick` on every execution except the last, which runs `last` instead.
+struct CountdownCallback<T, L> {
+    count: usize,
+    tick: T,
+    last: L,
+}
+
+#[async_trait]
+impl<T, L> TimerCallback for CountdownCallback<T, L>
+where
+    T: TimerCallback,
+    L: TimerCallback,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        Err(context_required())
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        if context.execution() >= self.count {
+            self.last.execute_with_context(context).await
+        } else {
+            self.tick.execute_with_context(context).await
+        }
+    }
+}
+
+/// Nudges the timer's interval so executions start once per `period`.
+struct RateTargetCallback<C> {
+    period: Duration,
+    min_interval: Duration,
+    max_interval: Duration,
+    last_start: std::sync::Mutex<Option<Instant>>,
+    callback: C,
+}
+
+impl<C> RateTargetCallback<C> {
+    /// Moves `interval` halfway towards the value that would have made the
+    /// last measured period equal the target.
+    fn next_interval(&self, interval: Duration, measured: Duration) -> Duration {
+        let correction = (self.period.as_secs_f64() - measured.as_secs_f64()) / 2.0;
+        let next = (interval.as_secs_f64() + correction).max(0.0);
+        Duration::try_from_secs_f64(next)
+            .unwrap_or(self.max_interval)
+            .clamp(self.min_interval, self.max_interval)
+    }
+}
+
+#[async_trait]
+impl<C> TimerCallback for RateTargetCallback<C>
+where
+    C: TimerCallback,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        Err(context_required())
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        let timer = context.timer();
+        let now = timer.inner.runtime.now();
+        let previous = self
+            .last_start
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .replace(now);
+
+        if let Some(previous) = previous {
+            let interval = timer.get_interval().await;
+            let next = self.next_interval(interval, now.saturating_duration_since(previous));
+            if next != interval {
+                timer.request_adjust_interval(next).await?;
+            }
+        }
+
+        self.callback.execute_with_context(context).await
+    }
+}
+
+/// Multiplies the timer's interval by `factor` after every execution, up to `ceiling`.
+struct DecayingCallback<C> {
+    factor: f64,
+    ceiling: Duration,
+    callback: C,
+}
+
+#[async_trait]
+impl<C> TimerCallback for DecayingCallback<C>
+where
+    C: TimerCallback,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        Err(context_required())
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        let timer = context.timer();
+        let interval = timer.get_interval().await;
+        let next = Duration::try_from_secs_f64(interval.as_secs_f64() * self.factor)
+            .unwrap_or(self.ceiling)
+            .min(self.ceiling);
+        if next != interval {
+            timer.request_adjust_interval(next).await?;
+        }
+
+        self.callback.execute_with_context(context).await
+    }
+}
+
+/// Completes the run once `probe` reports ready, and stops it at `deadline`.
+struct ReadinessProbeCallback<F> {
+    probe: F,
+    deadline: Instant,
+}
+
+#[async_trait]
+impl<F, Fut> TimerCallback for ReadinessProbeCallback<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<bool, TimerError>> + Send,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        Err(context_required())
+    }
+
+    async fn execute_with_context(&self, context: TimerContext) -> Result<(), TimerError> {
+        let result = (self.probe)().await;
+        let timer = context.timer();
+        if matches!(result, Ok(true)) {
+            timer.set_recurring(false).await?;
+            return Ok(());
+        }
+
+        if timer.inner.runtime.now() + timer.get_interval().await > self.deadline {
+            timer.request_stop().await?;
+        }
+        result.map(|_| ())
+    }
+}
+
+#[async_trait]
+impl<F, Fut> TimerCallback for F
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = Result<(), TimerError>> + Send,
+{
+    async fn execute(&self) -> Result<(), TimerError> {
+        (self)().await
+    }
+}
+
+pub(super) enum TimerCommand {
+    Pause,
+    Resume,
+    Stop,
+    Cancel,
+    SetInterval(Duration),
+    TriggerNow,
+    FireNow,
+}
+
+pub(super) type ErrorHandler = Arc<dyn Fn(&TimerError) + Send + Sync>;
+
+pub(super) struct TimerInner {
+    pub(super) state: Mutex<TimerState>,
+    pub(super) state_mirror: AtomicU8,
+    pub(super) state_tx: watch::Sender<TimerState>,
+    pub(super) handle: Mutex<Option<JoinHandle<()>>>,
+    pub(super) run_token: Mutex<CancellationToken>,
+    pub(super) next_fire: Mutex<Option<Instant>>,
+    pub(super) last_execution: Mutex<Option<Instant>>,
+    pub(super) created_at: Instant,
+    pub(super) created_at_system: SystemTime,
+    pub(super) command_tx: Mutex<Option<mpsc::UnboundedSender<TimerCommand>>>,
+    pub(super) interval: Mutex<Duration>,
+    pub(super) expiration_count: Mutex<Option<usize>>,
+    pub(super) metadata: Mutex<TimerMetadata>,
+    pub(super) statistics: Mutex<TimerStatistics>,
+    #[cfg(feature = "profiling")]
+    pub(super) statistics_contention: AtomicU64,
+    pub(super) parked_while_paused: AtomicBool,
+    pub(super) paused_nanos: AtomicU64,
+    /// Whether the current or most recent run has executed its callback.
+    pub(super) fired: AtomicBool,
+    pub(super) recurring: AtomicBool,
+    pub(super) pause_epoch: AtomicU64,
+    pub(super) skip_overlaps: AtomicBool,
+    pub(super) retry_policy: Mutex<Option<RetryPolicy>>,
+    #[cfg(feature = "profiling")]
+    pub(super) lock_profiler: profiling::LockProfiler,
+    pub(super) stats_level: AtomicU8,
+    pub(super) counters: ExecutionCounters,
+    pub(super) last_outcome: Mutex<Option<TimerOutcome>>,
+    pub(super) last_result: Mutex<Option<Result<(), TimerError>>>,
+    pub(super) drift_histogram: Mutex<DriftHistogram>,
+    pub(super) stop_hook: Mutex<Option<Arc<dyn TimerCallback>>>,
+    pub(super) complete_hook: Mutex<Option<Arc<dyn TimerCallback>>>,
+    pub(super) error_handler: Mutex<Option<ErrorHandler>>,
+    pub(super) finalizer: Mutex<Option<Box<dyn FnOnce() + Send>>>,
+    pub(super) history: Mutex<VecDeque<ExecutionRecord>>,
+    pub(super) last_config: Mutex<Option<RunConfig>>,
+    pub(super) last_callback: Mutex<Option<Arc<dyn TimerCallback>>>,
+    pub(super) registry: Mutex<Option<Weak<RegistryInner>>>,
+    /// Identifier under which the attached registry tracks this timer.
+    pub(super) registry_id: AtomicU64,
+    pub(super) priority: AtomicU8,
+    pub(super) jitter_rng: std::sync::Mutex<Option<SharedRng>>,
+    pub(super) custom_totals: std::sync::Mutex<BTreeMap<String, f64>>,
+    pub(super) completion_tx: watch::Sender<Option<TimerOutcome>>,
+    pub(super) event_tx: broadcast::Sender<TimerEvent>,
+    pub(super) event_capacity: usize,
+    pub(super) events_enabled: AtomicBool,
+    /// Signalled whenever a subscriber frees room in the event buffer.
+    pub(super) events_drained: Arc<Notify>,
+    pub(super) runtime: driver::RuntimeHandle,
+    pub(super) next_run_id: AtomicU64,
+    pub(super) active_run_id: AtomicU64,
+    /// Advanced whenever the statistics reset, so checkpoints can detect it.
+    pub(super) statistics_epoch: AtomicU64,
+}
+
+impl TimerInner {
+    pub(super) fn stats_level(&self) -> StatsLevel {
+        StatsLevel::from_u8(self.stats_level.load(Ordering::SeqCst))
+    }
+
+    /// Locks the statistics, counting acquisitions that had to wait.
+    pub(super) async fn lock_statistics(&self) -> Profiled<'_, TimerStatistics> {
+        #[cfg(feature = "profiling")]
+        let statistics = match self.statistics.try_lock() {
+            Ok(statistics) => statistics,
+            Err(_) => {
+                self.statistics_contention.fetch_add(1, Ordering::Relaxed);
+                self.statistics.lock().await
+            }
+        };
+        #[cfg(not(feature = "profiling"))]
+        let statistics = self.statistics.lock().await;
+        self.profile_guard(LockSite::Statistics, statistics)
+    }
+
+    pub(super) async fn set_state(&self, state: TimerState) {
+        let mut guard = self.lock_profiled(&self.state, LockSite::State).await;
+        *guard = state;
+        self.mirror_state(state);
+    }
+
+    fn mirror_state(&self, state: TimerState) {
+        self.state_mirror.store(state.to_u8(), Ordering::SeqCst);
+        self.state_tx.send_replace(state);
+    }
+}
+
+/// Leadership gate consulted before each execution.
+#[derive(Clone)]
+pub(super) struct Leadership(Arc<dyn Fn() -> bool + Send + Sync>);
+
+impl Leadership {
+    pub(super) fn is_leader(&self) -> bool {
+        (self.0)()
+    }
+}
+
+impl std::fmt::Debug for Leadership {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Leadership(..)")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct RunConfig {
+    pub(super) interval: Duration,
+    pub(super) start_deadline: Option<Instant>,
+    pub(super) initial_delay: Option<Duration>,
+    pub(super) immediate_first_tick: bool,
+    pub(super) union_schedules: Vec<RecurringSchedule>,
+    pub(super) cron: Option<CronSchedule>,
+    pub(super) jitter: Option<Duration>,
+    pub(super) callback_timeout: Option<Duration>,
+    pub(super) soft_timeout: Option<Duration>,
+    pub(super) history_capacity: usize,
+    pub(super) pause_on_full_events: bool,
+    pub(super) retry_policy: Option<RetryPolicy>,
+    pub(super) recurring: bool,
+    pub(super) cadence: RecurringCadence,
+    pub(super) expiration_count: Option<usize>,
+    pub(super) active_window: Option<(TimeOfDay, TimeOfDay)>,
+    pub(super) resume_catch_up: ResumeCatchUp,
+    pub(super) tolerance: Duration,
+    pub(super) count_errors_toward_limit: bool,
+    pub(super) leadership: Option<Leadership>,
+    pub(super) enabled_flag: Option<Arc<AtomicBool>>,
+    pub(super) stats_level: StatsLevel,
+    pub(super) metadata: TimerMetadata,
+}
+
+impl RunConfig {
+    fn once(delay: Duration, metadata: TimerMetadata) -> Self {
+        Self {
+            interval: delay,
+            start_deadline: None,
+            initial_delay: None,
+            immediate_first_tick: false,
+            union_schedules: Vec::new(),
+            cron: None,
+            jitter: None,
+            callback_timeout: None,
+            soft_timeout: None,
+            history_capacity: 0,
+            pause_on_full_events: false,
+            retry_policy: None,
+            recurring: false,
+            cadence: RecurringCadence::FixedDelay,
+            expiration_count: None,
+            active_window: None,
+            resume_catch_up: ResumeCatchUp::ContinueOnSchedule,
+            tolerance: Duration::ZERO,
+            count_errors_toward_limit: true,
+            leadership: None,
+            enabled_flag: None,
+            stats_level: StatsLevel::Full,
+            metadata,
+        }
+    }
+
+    fn at(deadline: Instant, now: Instant, metadata: TimerMetadata) -> Self {
+        Self {
+            start_deadline: Some(deadline),
+            ..Self::once(deadline.saturating_duration_since(now), metadata)
+        }
+    }
+
+    fn recurring(schedule: RecurringSchedule, metadata: TimerMetadata) -> Self {
+        Self {
+            interval: schedule.interval,
+            initial_delay: schedule.initial_delay,
+            immediate_first_tick: schedule.immediate_first_tick,
+            jitter: schedule.jitter,
+            recurring: true,
+            cadence: schedule.cadence,
+            expiration_count: schedule.expiration_count,
+            active_window: schedule.active_window,
+            resume_catch_up: schedule.resume_catch_up,
+            tolerance: schedule.tolerance,
+            count_errors_toward_limit: schedule.count_errors_toward_limit,
+            ..Self::once(schedule.interval, metadata)
+        }
+    }
+
+    /// Rejects configurations that cannot run, reporting the first problem found.
+    fn validate(&self) -> Result<(), TimerError> {
+        if self.interval.is_zero() && self.start_deadline.is_none() {
+            return Err(TimerError::invalid_parameter(
+                "Interval must be greater than zero.",
+            ));
+        }
+
+        if self.recurring && matches!(self.expiration_count, Some(0)) {
+            return Err(TimerError::invalid_parameter(
+                "Expiration count must be greater than zero.",
+            ));
+        }
+
+        if self.initial_delay.is_some_and(|delay| delay.is_zero()) {
+            return Err(TimerError::invalid_parameter(
+                "Initial delay must be greater than zero.",
+            ));
+        }
+
+        if self.immediate_first_tick && self.initial_delay.is_some() {
+            return Err(TimerError::invalid_parameter(
+                "An immediate first tick cannot be combined with an initial delay.",
+            ));
+        }
+
+        for schedule in &self.union_schedules {
+            RunConfig::recurring(*schedule, TimerMetadata::default()).validate()?;
+        }
+
+        if self.active_window.is_some_and(|(start, end)| start == end) {
+            return Err(TimerError::invalid_parameter(
+                "Active window start and end must differ.",
+            ));
+        }
+
+        if self.jitter.is_some_and(|jitter| jitter.is_zero()) {
+            return Err(TimerError::invalid_parameter(
+                "Jitter must be greater than zero.",
+            ));
+        }
+
+        if self
+            .callback_timeout
+            .is_some_and(|timeout| timeout.is_zero())
+        {
+            return Err(TimerError::invalid_parameter(
+                "Callback timeout must be greater than zero.",
+            ));
+        }
+
+        if self.soft_timeout.is_some_and(|timeout| timeout.is_zero()) {
+            return Err(TimerError::invalid_parameter(
+                "Soft timeout must be greater than zero.",
+            ));
+        }
+
+        if let (Some(soft), Some(hard)) = (self.soft_timeout, self.callback_timeout) {
+            if soft >= hard {
+                return Err(TimerError::invalid_parameter(
+                    "Soft timeout must be shorter than the callback timeout.",
+                ));
+            }
+        }
+
+        validate_retry_policy(self.retry_policy)
+    }
+}
+
+fn validate_retry_policy(retry_policy: Option<RetryPolicy>) -> Result<(), TimerError> {
+    if retry_policy.is_some_and(|policy| {
+        matches!(
+            policy.backoff(),
+            RetryBackoff::Fixed(duration)
+                | RetryBackoff::Linear(duration)
+                | RetryBackoff::Exponential(duration) if duration.is_zero()
+        )
+    }) {
+        return Err(TimerError::invalid_parameter(
+            "Retry backoff must be greater than zero.",
+        ));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum TimerKind {
+    Once(Duration),
+    At(Instant),
+    Recurring(RecurringSchedule),
+}
+
+/// Builds and starts a timer with less boilerplate.
+pub struct TimerBuilder {
+    kind: TimerKind,
+    callback_timeout: Option<Duration>,
+    soft_timeout: Option<Duration>,
+    history_capacity: usize,
+    pause_on_full_events: bool,
+    retry_policy: Option<RetryPolicy>,
+    leadership: Option<Leadership>,
+    enabled_flag: Option<Arc<AtomicBool>>,
+    // Wrapped so the builder stays `Sync` without requiring a `Sync` closure.
+    finalizer: Option<std::sync::Mutex<Box<dyn FnOnce() + Send>>>,
+    priority: u8,
+    stats_level: StatsLevel,
+    event_capacity: usize,
+    start_paused: bool,
+    events_enabled: bool,
+    metadata: TimerMetadata,
+}
+
+#[derive(Clone)]
+/// Timer handle for managing one-time and recurring tasks.
+pub struct Timer {
+    inner: Arc<TimerInner>,
+}
+
+impl Default for Timer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Non-owning timer handle that does not keep the timer alive.
+///
+/// Observers can hold a `WeakTimer` without preventing the timer from being
+/// dropped once every [`Timer`] handle, including the registry's, is gone.
+#[derive(Clone)]
+pub struct WeakTimer {
+    inner: Weak<TimerInner>,
+}
+
+impl WeakTimer {
+    /// Returns a strong handle if the timer is still alive.
+    pub fn upgrade(&self) -> Option<Timer> {
+        self.inner.upgrade().map(|inner| Timer { inner })
+    }
+}
+
+impl Timer {
+    /// Priority of a timer that never had one set, leaving room on both sides.
+    pub const DEFAULT_PRIORITY: u8 = 128;
+
+    /// Creates a non-owning handle to this timer.
+    pub fn downgrade(&self) -> WeakTimer {
+        WeakTimer {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Creates a new timer.
+    pub fn new() -> Self {
+        Self::new_with_runtime(driver::RuntimeHandle::default(), true)
+    }
+
+    /// Creates a new timer with broadcast events disabled.
+    pub fn new_silent() -> Self {
+        Self::new_with_runtime(driver::RuntimeHandle::default(), false)
+    }
+
+    pub(crate) fn new_with_runtime(runtime: driver::RuntimeHandle, events_enabled: bool) -> Self {
+        Self::new_with_event_capacity(runtime, events_enabled, TIMER_EVENT_BUFFER)
+    }
+
+    fn new_with_event_capacity(
+        runtime: driver::RuntimeHandle,
+        events_enabled: bool,
+        event_capacity: usize,
+    ) -> Self {
+        let (completion_tx, _completion_rx) = watch::channel(None);
+        let (state_tx, _state_rx) = watch::channel(TimerState::Stopped);
+        let (event_tx, _event_rx) = broadcast::channel(event_capacity);
 
         Self {
             inner: Arc::new(TimerInner {
                 state: Mutex::new(TimerState::Stopped),
+                state_mirror: AtomicU8::new(TimerState::Stopped.to_u8()),
+                state_tx,
                 handle: Mutex::new(None),
+                run_token: Mutex::new(CancellationToken::new()),
+                next_fire: Mutex::new(None),
+                last_execution: Mutex::new(None),
+                created_at: runtime.now(),
+                created_at_system: runtime.system_now(),
                 command_tx: Mutex::new(None),
                 interval: Mutex::new(Duration::ZERO),
                 expiration_count: Mutex::new(None),
                 metadata: Mutex::new(TimerMetadata::default()),
                 statistics: Mutex::new(TimerStatistics::default()),
+                #[cfg(feature = "profiling")]
+                statistics_contention: AtomicU64::new(0),
+                parked_while_paused: AtomicBool::new(false),
+                paused_nanos: AtomicU64::new(0),
+                fired: AtomicBool::new(false),
+                recurring: AtomicBool::new(false),
+                pause_epoch: AtomicU64::new(0),
+                skip_overlaps: AtomicBool::new(false),
+                retry_policy: Mutex::new(None),
+                #[cfg(feature = "profiling")]
+                lock_profiler: profiling::LockProfiler::default(),
+                stats_level: AtomicU8::new(StatsLevel::Full.to_u8()),
+                counters: ExecutionCounters::default(),
                 last_outcome: Mutex::new(None),
+                stop_hook: Mutex::new(None),
+                complete_hook: Mutex::new(None),
+                error_handler: Mutex::new(None),
+                finalizer: Mutex::new(None),
+                last_result: Mutex::new(None),
+                drift_histogram: Mutex::new(DriftHistogram::default()),
+                history: Mutex::new(VecDeque::new()),
+                last_config: Mutex::new(None),
+                last_callback: Mutex::new(None),
+                registry: Mutex::new(None),
+                registry_id: AtomicU64::new(0),
+                priority: AtomicU8::new(Timer::DEFAULT_PRIORITY),
+                jitter_rng: std::sync::Mutex::new(None),
+                custom_totals: std::sync::Mutex::new(BTreeMap::new()),
                 completion_tx,
                 event_tx,
+                event_capacity,
                 events_enabled: AtomicBool::new(events_enabled),
+                events_drained: Arc::new(Notify::new()),
                 runtime,
                 next_run_id: AtomicU64::new(1),
                 active_run_id: AtomicU64::new(0),
+                statistics_epoch: AtomicU64::new(0),
             }),
         }
     }
@@ -553,9 +1834,18 @@ impl Timer {
     }
 
     /// Subscribes to future timer events.
+    ///
+    /// Every subscriber receives its own copy of each event emitted after it
+    /// subscribed: starts, ticks, callback failures, pauses, resumes, interval
+    /// adjustments, and the final outcome, which tells natural completion from
+    /// stops and cancellations through [`TimerOutcome::reason`]. Subscribers
+    /// never slow the timer down; one that falls behind skips events as
+    /// described on [`TimerEvents`].
     pub fn subscribe(&self) -> TimerEvents {
         TimerEvents {
             receiver: self.inner.event_tx.subscribe(),
+            lagged: 0,
+            drained: DrainSignal(Arc::clone(&self.inner.events_drained)),
         }
     }
 
@@ -579,23 +1869,8 @@ impl Timer {
         F: TimerCallback + 'static,
     {
         let metadata = self.inner.metadata.lock().await.clone();
-        self.start_internal(
-            RunConfig {
-                interval: delay,
-                start_deadline: None,
-                initial_delay: None,
-                jitter: None,
-                callback_timeout: None,
-                retry_policy: None,
-                recurring: false,
-                cadence: RecurringCadence::FixedDelay,
-                expiration_count: None,
-                metadata,
-            },
-            callback,
-            false,
-        )
-        .await
+        self.start_internal(RunConfig::once(delay, metadata), callback, false)
+            .await
     }
 
     /// Starts a one-time timer from an async closure.
@@ -618,23 +1893,31 @@ impl Timer {
     {
         let now = self.inner.runtime.now();
         let metadata = self.inner.metadata.lock().await.clone();
-        self.start_internal(
-            RunConfig {
-                interval: deadline.saturating_duration_since(now),
-                start_deadline: Some(deadline),
-                initial_delay: None,
-                jitter: None,
-                callback_timeout: None,
-                retry_policy: None,
-                recurring: false,
-                cadence: RecurringCadence::FixedDelay,
-                expiration_count: None,
-                metadata,
-            },
-            callback,
-            false,
-        )
-        .await
+        self.start_internal(RunConfig::at(deadline, now, metadata), callback, false)
+            .await
+    }
+
+    /// Starts a one-time timer that fires at the wall-clock time `when`.
+    ///
+    /// The delay is computed once, when the timer starts; later wall-clock
+    /// adjustments such as NTP corrections do not move the deadline. Returns
+    /// an invalid-parameter error if `when` is already in the past.
+    pub async fn start_at_system_time<F>(
+        &self,
+        when: SystemTime,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        let delay = when
+            .duration_since(self.inner.runtime.system_now())
+            .map_err(|_| TimerError::invalid_parameter("Start time must not be in the past."))?;
+        let deadline =
+            self.inner.runtime.now().checked_add(delay).ok_or_else(|| {
+                TimerError::invalid_parameter("Start time is too far in the future.")
+            })?;
+        self.start_at(deadline, callback).await
     }
 
     /// Starts a one-time timer from an async closure at the provided deadline.
@@ -660,25 +1943,279 @@ impl Timer {
         F: TimerCallback + 'static,
     {
         let metadata = self.inner.metadata.lock().await.clone();
-        self.start_internal(
-            RunConfig {
-                interval: schedule.interval,
-                start_deadline: None,
-                initial_delay: schedule.initial_delay,
-                jitter: schedule.jitter,
-                callback_timeout: None,
-                retry_policy: None,
-                recurring: true,
-                cadence: schedule.cadence,
-                expiration_count: schedule.expiration_count,
-                metadata,
+        self.start_internal(RunConfig::recurring(schedule, metadata), callback, false)
+            .await
+    }
+
+    /// Starts a recurring timer whose first execution happens immediately.
+    ///
+    /// Equivalent to [`Timer::start_recurring`] with
+    /// [`RecurringSchedule::with_immediate_first_tick`].
+    pub async fn start_recurring_immediate<F>(
+        &self,
+        schedule: RecurringSchedule,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        self.start_recurring(schedule.with_immediate_first_tick(), callback)
+            .await
+    }
+
+    /// Starts a recurring timer that fires on the union of several schedules.
+    ///
+    /// Each schedule contributes fire times at its initial delay (or its
+    /// interval when no delay is set) and every interval after that. The
+    /// timer fires at the earliest pending time across all schedules; fires
+    /// from different schedules within a few milliseconds of each other run
+    /// the callback once. Apart from the interval and initial delay, settings
+    /// such as the expiration count and active window come from the first
+    /// schedule. Union timers do not support [`Timer::adjust_interval`].
+    pub async fn start_union<F>(
+        &self,
+        schedules: Vec<RecurringSchedule>,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        let Some(first) = schedules.first().copied() else {
+            return Err(TimerError::invalid_parameter(
+                "A union timer needs at least one schedule.",
+            ));
+        };
+        let interval = schedules
+            .iter()
+            .map(|schedule| schedule.interval)
+            .min()
+            .unwrap_or(first.interval);
+
+        let metadata = self.inner.metadata.lock().await.clone();
+        let mut config = RunConfig::recurring(
+            RecurringSchedule {
+                interval,
+                initial_delay: None,
+                ..first
+            },
+            metadata,
+        );
+        config.union_schedules = schedules;
+        self.start_internal(config, callback, false).await
+    }
+
+    /// Starts a recurring timer that fires whenever a five-field cron expression matches.
+    ///
+    /// Fire times are computed in UTC from the wall clock after every tick,
+    /// so ticks missed while paused or while the callback overran are skipped
+    /// rather than caught up. The reported interval is the gap between the
+    /// first two fire times. Cron timers can be paused, resumed, and stopped
+    /// like any other, but do not support [`Timer::adjust_interval`]. See
+    /// [`CronSchedule`] for the accepted syntax.
+    pub async fn start_cron<F>(&self, expression: &str, callback: F) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        let cron = CronSchedule::parse(expression)?;
+        let never_fires = || {
+            TimerError::invalid_parameter(format!("Cron expression '{expression}' never fires."))
+        };
+        let first = cron
+            .next_after(self.inner.runtime.system_now())
+            .ok_or_else(never_fires)?;
+        let second = cron.next_after(first).ok_or_else(never_fires)?;
+        let interval = second.duration_since(first).unwrap_or_default();
+
+        let metadata = self.inner.metadata.lock().await.clone();
+        let mut config = RunConfig::recurring(RecurringSchedule::new(interval), metadata);
+        config.cron = Some(cron);
+        self.start_internal(config, callback, false).await
+    }
+
+    /// Starts a countdown that executes `count` times, every `interval`.
+    ///
+    /// The first `count - 1` executions run `tick_callback`; the last one
+    /// runs `final_callback` instead, after which the run completes.
+    pub async fn start_countdown<T, L>(
+        &self,
+        interval: Duration,
+        count: usize,
+        tick_callback: T,
+        final_callback: L,
+    ) -> Result<u64, TimerError>
+    where
+        T: TimerCallback + 'static,
+        L: TimerCallback + 'static,
+    {
+        self.start_recurring(
+            RecurringSchedule::new(interval).with_expiration_count(count),
+            CountdownCallback {
+                count,
+                tick: tick_callback,
+                last: final_callback,
+            },
+        )
+        .await
+    }
+
+    /// Starts a recurring timer that adjusts its interval to hit a target rate.
+    ///
+    /// Before each execution the timer measures the time since the previous
+    /// one and moves its interval halfway towards the value that would have
+    /// produced `target_per_minute` executions per minute, so slow callbacks
+    /// are compensated for. The interval always stays within
+    /// `min_interval..=max_interval`.
+    pub async fn start_rate_targeted<F>(
+        &self,
+        target_per_minute: u32,
+        min_interval: Duration,
+        max_interval: Duration,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        if target_per_minute == 0 {
+            return Err(TimerError::invalid_parameter(
+                "Target rate must be greater than zero.",
+            ));
+        }
+        if min_interval.is_zero() || min_interval > max_interval {
+            return Err(TimerError::invalid_parameter(
+                "Rate-targeted interval bounds must satisfy 0 < min <= max.",
+            ));
+        }
+
+        let period = Duration::from_secs(60) / target_per_minute;
+        self.start_recurring(
+            RecurringSchedule::new(period.clamp(min_interval, max_interval)),
+            RateTargetCallback {
+                period,
+                min_interval,
+                max_interval,
+                last_start: std::sync::Mutex::new(None),
+                callback,
             },
-            callback,
-            false,
         )
         .await
     }
 
+    /// Starts a recurring timer whose interval grows geometrically up to a ceiling.
+    ///
+    /// The first execution happens after `initial`; every execution then
+    /// multiplies the interval by `factor` until it reaches `ceiling`, where
+    /// it stays. Useful for polling that starts eager and relaxes over time.
+    pub async fn start_decaying<F>(
+        &self,
+        initial: Duration,
+        factor: f64,
+        ceiling: Duration,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        if !factor.is_finite() || factor < 1.0 {
+            return Err(TimerError::invalid_parameter(
+                "Decay factor must be a finite number of at least 1.0.",
+            ));
+        }
+        if initial > ceiling {
+            return Err(TimerError::invalid_parameter(
+                "Initial interval must not exceed the ceiling.",
+            ));
+        }
+
+        self.start_recurring(
+            RecurringSchedule::new(initial),
+            DecayingCallback {
+                factor,
+                ceiling,
+                callback,
+            },
+        )
+        .await
+    }
+
+    /// Starts a readiness probe that runs `probe` immediately and then every `interval`.
+    ///
+    /// The run completes as soon as `probe` returns `Ok(true)`. If the next
+    /// probe would fall after `deadline`, measured from now, the run stops
+    /// instead, so [`TimerOutcome::reason`] is
+    /// [`TimerFinishReason::Completed`] when ready and
+    /// [`TimerFinishReason::Stopped`] when the deadline passed. Probe errors
+    /// are recorded as failed executions and probing continues; a probe that
+    /// reports not ready still counts as a successful execution.
+    pub async fn start_readiness_probe<F, Fut>(
+        &self,
+        interval: Duration,
+        deadline: Duration,
+        probe: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<bool, TimerError>> + Send + 'static,
+    {
+        let deadline = self
+            .inner
+            .runtime
+            .now()
+            .checked_add(deadline)
+            .ok_or_else(|| TimerError::invalid_parameter("Readiness deadline is too far away."))?;
+        self.start_recurring(
+            RecurringSchedule::new(interval).with_immediate_first_tick(),
+            ReadinessProbeCallback { probe, deadline },
+        )
+        .await
+    }
+
+    /// Starts a recurring timer that also fires whenever `trigger_rx` receives a message.
+    ///
+    /// The timer fires at least every `interval`. Each trigger fires it
+    /// immediately and the interval restarts after every execution, so a
+    /// trigger is never followed by a second fire shortly afterwards.
+    /// Triggers received while the timer is paused are dropped. The trigger
+    /// channel is no longer read once the run finishes.
+    pub async fn start_hybrid<F, T>(
+        &self,
+        interval: Duration,
+        mut trigger_rx: mpsc::Receiver<T>,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+        T: Send + 'static,
+    {
+        let run_id = self
+            .start_recurring(RecurringSchedule::new(interval), callback)
+            .await?;
+        let timer = self.clone();
+        let mut completion = self.inner.completion_tx.subscribe();
+        drop(self.inner.runtime.spawn(async move {
+            let run_finished = async move {
+                let _ = completion
+                    .wait_for(|outcome| {
+                        outcome
+                            .as_ref()
+                            .is_some_and(|outcome| outcome.run_id == run_id)
+                    })
+                    .await;
+            };
+            tokio::pin!(run_finished);
+            loop {
+                let trigger = tokio::select! {
+                    _ = &mut run_finished => break,
+                    trigger = trigger_rx.recv() => trigger,
+                };
+                if trigger.is_none() || timer.active_run_id().await != Some(run_id) {
+                    break;
+                }
+                let _ = timer.trigger_now().await;
+            }
+        }));
+        Ok(run_id)
+    }
+
     /// Starts a recurring timer from an async closure.
     pub async fn start_recurring_fn<F, Fut>(
         &self,
@@ -692,7 +2229,136 @@ impl Timer {
         self.start_recurring(schedule, callback).await
     }
 
+    /// Starts a new run with the configuration of the most recent run.
+    ///
+    /// This also starts timers created by [`Timer::duplicate_config`].
+    pub async fn restart_with<F>(&self, callback: F) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        let config = self.inner.last_config.lock().await.clone().ok_or_else(|| {
+            TimerError::invalid_parameter("Timer has no previous configuration to reuse.")
+        })?;
+        self.start_internal(config, callback, false).await
+    }
+
+    /// Starts a new run with the configuration and callback of the most recent run.
+    ///
+    /// Statistics start from zero, as for any new run; use
+    /// [`Timer::restart_preserving_stats`] to keep counting. A running timer
+    /// is replaced by the new run. The timer keeps its most recent callback
+    /// for this purpose, so a callback that owns a strong handle to its own
+    /// timer keeps the timer alive; capture a [`WeakTimer`] there instead.
+    pub async fn restart(&self) -> Result<u64, TimerError> {
+        self.restart_internal(false).await
+    }
+
+    /// Like [`Timer::restart`], but keeps accumulating the previous run's
+    /// statistics and custom metric totals.
+    ///
+    /// `elapsed_time` still measures the new run only.
+    pub async fn restart_preserving_stats(&self) -> Result<u64, TimerError> {
+        self.restart_internal(true).await
+    }
+
+    async fn restart_internal(&self, preserve_statistics: bool) -> Result<u64, TimerError> {
+        let missing = || TimerError::invalid_parameter("Timer has no previous run to restart.");
+        let config = self
+            .inner
+            .last_config
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(missing)?;
+        let callback = self
+            .inner
+            .last_callback
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(missing)?;
+        self.start_shared(config, callback, false, preserve_statistics)
+            .await
+    }
+
+    /// Starts a timer described by `config` with `callback`.
+    pub async fn from_config<F>(config: &TimerConfig, callback: F) -> Result<Timer, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        let timer = Timer::new();
+        timer.start_config(config, callback).await?;
+        Ok(timer)
+    }
+
+    /// Starts the timer with a persisted configuration.
+    ///
+    /// A configured name replaces the timer's label.
+    pub async fn start_config<F>(
+        &self,
+        config: &TimerConfig,
+        callback: F,
+    ) -> Result<u64, TimerError>
+    where
+        F: TimerCallback + 'static,
+    {
+        let metadata = self.inner.metadata.lock().await.clone();
+        self.start_internal(config.run_config(metadata)?, callback, false)
+            .await
+    }
+
+    /// Captures the timer's schedule so it can be persisted and recreated
+    /// with [`Timer::from_config`].
+    ///
+    /// Reflects the current or most recent run; a timer that was never
+    /// started reports a one-shot configuration.
+    pub async fn to_config(&self) -> TimerConfig {
+        let recurring = self
+            .inner
+            .last_config
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|config| config.recurring);
+        TimerConfig {
+            interval: self.get_interval().await,
+            recurring,
+            expiration_count: self.get_expiration_count().await,
+            name: self.label().await,
+        }
+    }
+
+    /// Creates an independent, stopped timer with the same configuration.
+    ///
+    /// Unlike `clone()`, which returns another handle to the same timer, the
+    /// duplicate has its own state, statistics, events, and history. Start it
+    /// with [`Timer::restart_with`].
+    pub async fn duplicate_config(&self) -> Timer {
+        let duplicate = Self::new_with_event_capacity(
+            self.inner.runtime.clone(),
+            self.inner.events_enabled.load(Ordering::SeqCst),
+            self.inner.event_capacity,
+        );
+        let config = self.inner.last_config.lock().await.clone();
+
+        *duplicate.inner.interval.lock().await = self.get_interval().await;
+        *duplicate.inner.expiration_count.lock().await = self.get_expiration_count().await;
+        *duplicate.inner.metadata.lock().await = self.metadata().await;
+        duplicate.set_priority(self.priority());
+        if let Some(config) = &config {
+            if config.history_capacity > 0 {
+                *duplicate.inner.history.lock().await =
+                    VecDeque::with_capacity(config.history_capacity);
+            }
+        }
+        *duplicate.inner.last_config.lock().await = config;
+        duplicate
+    }
+
     /// Pauses a running timer.
+    ///
+    /// Pause and resume requests are queued for the run task in order, so a
+    /// resume issued before the task observed the pause is never lost.
     pub async fn pause(&self) -> Result<(), TimerError> {
         self.ensure_not_reentrant(
             "pause() cannot be awaited from the timer's active callback; use request_pause().",
@@ -702,7 +2368,15 @@ impl Timer {
 
     /// Requests that the current run pause after the current callback or sleep edge.
     pub async fn request_pause(&self) -> Result<(), TimerError> {
-        let _run_id = self
+        self.pause_run().await.map(|_| ())
+    }
+
+    /// Pauses the current run and returns its identifier and the new pause epoch.
+    ///
+    /// The epoch is read under the state lock, so it identifies this pause
+    /// even if another pause or resume follows right away.
+    async fn pause_run(&self) -> Result<(u64, u64), TimerError> {
+        let run_id = self
             .active_run_id()
             .await
             .ok_or_else(TimerError::not_running)?;
@@ -712,13 +2386,85 @@ impl Timer {
         }
 
         *state = TimerState::Paused;
+        self.inner.mirror_state(TimerState::Paused);
+        let epoch = self.inner.pause_epoch.fetch_add(1, Ordering::SeqCst) + 1;
         drop(state);
 
         self.send_command(TimerCommand::Pause).await;
 
         #[cfg(feature = "logging")]
-        debug!("Timer paused.");
+        debug!("{} paused.", self.log_name().await);
+
+        Ok((run_id, epoch))
+    }
+
+    /// Pauses a running timer and resumes it automatically after `duration`.
+    ///
+    /// The automatic resume is dropped if the timer was resumed, paused
+    /// again, or stopped in the meantime, so a manual change always wins.
+    /// The task waiting to resume exits as soon as the run ends.
+    pub async fn pause_for(&self, duration: Duration) -> Result<(), TimerError> {
+        self.ensure_not_reentrant(
+            "pause_for() cannot be awaited from the timer's active callback; use request_pause().",
+        )?;
+        let (run_id, epoch) = self.pause_run().await?;
+        let run_ended = self.inner.run_token.lock().await.clone();
+        let runtime = self.inner.runtime.clone();
+        let Some(deadline) = runtime.now().checked_add(duration) else {
+            // Too far in the future to ever be reached.
+            return Ok(());
+        };
+
+        let timer = self.downgrade();
+        drop(self.inner.runtime.spawn(async move {
+            tokio::select! {
+                _ = runtime.sleep_until(deadline) => {}
+                _ = run_ended.cancelled() => return,
+            }
+            if let Some(timer) = timer.upgrade() {
+                if timer.active_run_id().await == Some(run_id)
+                    && timer.inner.pause_epoch.load(Ordering::SeqCst) == epoch
+                {
+                    let _ = timer.request_resume().await;
+                }
+            }
+        }));
+        Ok(())
+    }
+
+    /// Schedules the current run to pause at the wall-clock time `when`.
+    ///
+    /// A time in the past pauses the timer right away. The scheduled pause is
+    /// dropped if the run has ended by then, and has no effect if the timer
+    /// is already paused. The task waiting to pause exits as soon as the run
+    /// ends.
+    pub async fn pause_at(&self, when: SystemTime) -> Result<(), TimerError> {
+        let run_id = self
+            .active_run_id()
+            .await
+            .ok_or_else(TimerError::not_running)?;
+        let run_ended = self.inner.run_token.lock().await.clone();
+        let runtime = self.inner.runtime.clone();
+        let delay = when
+            .duration_since(runtime.system_now())
+            .unwrap_or(Duration::ZERO);
+        let Some(deadline) = runtime.now().checked_add(delay) else {
+            // Too far in the future to ever be reached.
+            return Ok(());
+        };
 
+        let timer = self.downgrade();
+        drop(self.inner.runtime.spawn(async move {
+            tokio::select! {
+                _ = runtime.sleep_until(deadline) => {}
+                _ = run_ended.cancelled() => return,
+            }
+            if let Some(timer) = timer.upgrade() {
+                if timer.active_run_id().await == Some(run_id) {
+                    let _ = timer.request_pause().await;
+                }
+            }
+        }));
         Ok(())
     }
 
@@ -742,17 +2488,133 @@ impl Timer {
         }
 
         *state = TimerState::Running;
+        self.inner.mirror_state(TimerState::Running);
+        self.inner.pause_epoch.fetch_add(1, Ordering::SeqCst);
         drop(state);
 
         self.send_command(TimerCommand::Resume).await;
 
         #[cfg(feature = "logging")]
-        debug!("Timer resumed.");
+        debug!("{} resumed.", self.log_name().await);
+
+        Ok(())
+    }
+
+    /// Switches the current run between recurring and one-shot mode.
+    ///
+    /// The run checks the mode after every tick: a demoted recurring timer
+    /// completes after its next execution, and a promoted one-shot keeps
+    /// firing every interval. The change lasts for the current run only.
+    pub async fn set_recurring(&self, recurring: bool) -> Result<(), TimerError> {
+        self.active_run_id()
+            .await
+            .ok_or_else(TimerError::not_running)?;
+        self.inner.recurring.store(recurring, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Sets how ticks that come due while the callback is running are handled.
+    ///
+    /// The policy applies from the next scheduled tick and persists across
+    /// runs. Only fixed-rate timers are affected.
+    pub fn set_overlap_policy(&self, policy: OverlapPolicy) {
+        self.inner
+            .skip_overlaps
+            .store(policy == OverlapPolicy::Skip, Ordering::SeqCst);
+    }
+
+    /// Returns how ticks that come due while the callback is running are handled.
+    pub fn overlap_policy(&self) -> OverlapPolicy {
+        if self.inner.skip_overlaps.load(Ordering::SeqCst) {
+            OverlapPolicy::Skip
+        } else {
+            OverlapPolicy::Queue
+        }
+    }
+
+    /// Sets the timer's dispatch priority; higher priorities run first.
+    ///
+    /// Only consulted by a registry with
+    /// [`TimerRegistry::set_priority_ordering`] enabled, and only among timers
+    /// that fall due within its priority window. Defaults to
+    /// [`Timer::DEFAULT_PRIORITY`].
+    pub fn set_priority(&self, priority: u8) {
+        self.inner.priority.store(priority, Ordering::SeqCst);
+    }
+
+    /// Returns the timer's dispatch priority.
+    pub fn priority(&self) -> u8 {
+        self.inner.priority.load(Ordering::SeqCst)
+    }
+
+    /// Replaces the retry policy of the current run, or disables retries with `None`.
+    ///
+    /// The policy applies from the next tick onwards; a tick that is already
+    /// retrying finishes with the policy it started with. The change lasts for
+    /// the current run only.
+    pub async fn set_retry_policy(
+        &self,
+        retry_policy: Option<RetryPolicy>,
+    ) -> Result<(), TimerError> {
+        validate_retry_policy(retry_policy)?;
+        self.active_run_id()
+            .await
+            .ok_or_else(TimerError::not_running)?;
+        *self.inner.retry_policy.lock().await = retry_policy;
+        Ok(())
+    }
+
+    /// Fires the pending tick immediately instead of waiting for its deadline.
+    ///
+    /// A one-shot timer completes after the triggered execution; a recurring
+    /// timer continues on its schedule afterwards. A trigger requested while
+    /// a callback is executing is coalesced with that execution.
+    pub async fn trigger_now(&self) -> Result<(), TimerError> {
+        let _run_id = self
+            .active_run_id()
+            .await
+            .ok_or_else(TimerError::not_running)?;
+        if *self.inner.state.lock().await != TimerState::Running {
+            return Err(TimerError::not_running());
+        }
+
+        self.send_command(TimerCommand::TriggerNow).await;
+
+        #[cfg(feature = "logging")]
+        debug!("{} triggered.", self.log_name().await);
+
+        Ok(())
+    }
+
+    /// Runs the callback once immediately, outside the regular schedule.
+    ///
+    /// Unlike [`Timer::trigger_now`], the pending tick keeps its deadline, so
+    /// the regular interval timing is unaffected. The extra execution counts
+    /// toward `execution_count` but not toward the expiration count.
+    /// Executions are serialized by the run loop: a request made while a
+    /// callback is executing runs after that execution finishes, never
+    /// concurrently with it, and one that races with a pause runs once the
+    /// timer resumes.
+    pub async fn fire_now(&self) -> Result<(), TimerError> {
+        self.active_run_id()
+            .await
+            .ok_or_else(TimerError::not_running)?;
+        if *self.inner.state.lock().await != TimerState::Running {
+            return Err(TimerError::not_running());
+        }
+
+        self.send_command(TimerCommand::FireNow).await;
+
+        #[cfg(feature = "logging")]
+        debug!("{} fired out of cycle.", self.log_name().await);
 
         Ok(())
     }
 
     /// Stops the timer after the current callback finishes.
+    ///
+    /// The run's [`TimerContext::cancellation_token`] is cancelled so a
+    /// cooperative callback can finish early.
     pub async fn stop(&self) -> Result<TimerOutcome, TimerError> {
         self.ensure_not_reentrant(
             "stop() cannot be awaited from the timer's active callback; use request_stop().",
@@ -765,12 +2627,43 @@ impl Timer {
         self.join_run(run_id).await
     }
 
+    /// Stops the timer after the current callback finishes, aborting the
+    /// callback if that takes longer than `timeout`.
+    ///
+    /// The outcome's reason tells the two apart: [`TimerFinishReason::Stopped`]
+    /// when the run wound down gracefully and [`TimerFinishReason::Cancelled`]
+    /// when the callback had to be aborted. [`Duration::MAX`] waits for the
+    /// callback without a deadline.
+    pub async fn stop_graceful(&self, timeout: Duration) -> Result<TimerOutcome, TimerError> {
+        self.ensure_not_reentrant(
+            "stop_graceful() cannot be awaited from the timer's active callback; use request_stop().",
+        )?;
+        let run_id = self
+            .active_run_id()
+            .await
+            .ok_or_else(TimerError::not_running)?;
+        self.request_stop().await?;
+
+        let deadline = runtime::deadline_after(&self.inner, timeout);
+        tokio::select! {
+            outcome = self.join_run(run_id) => outcome,
+            _ = self.inner.runtime.sleep_until(deadline) => {
+                match self.cancel_with_reason(TimerFinishReason::Cancelled).await {
+                    Ok(outcome) => Ok(outcome),
+                    // The run finished between the deadline and the abort.
+                    Err(_) => self.join_run(run_id).await,
+                }
+            }
+        }
+    }
+
     /// Requests a graceful stop without waiting for the outcome.
     pub async fn request_stop(&self) -> Result<(), TimerError> {
         self.active_run_id()
             .await
             .ok_or_else(TimerError::not_running)?;
         self.send_command(TimerCommand::Stop).await;
+        self.inner.run_token.lock().await.cancel();
         Ok(())
     }
 
@@ -788,10 +2681,17 @@ impl Timer {
             .await
             .ok_or_else(TimerError::not_running)?;
         self.send_command(TimerCommand::Cancel).await;
+        self.inner.run_token.lock().await.cancel();
         Ok(())
     }
 
     /// Adjusts the interval of a running or paused timer.
+    ///
+    /// An interval of [`Duration::MAX`] parks the timer: it keeps running but
+    /// does not fire again until the interval is adjusted back down. Union
+    /// and cron timers derive their fire times from their schedules and
+    /// cannot be adjusted; the attempt fails with
+    /// [`TimerError::invalid_parameter`].
     pub async fn adjust_interval(&self, new_interval: Duration) -> Result<(), TimerError> {
         self.ensure_not_reentrant(
             "adjust_interval() cannot be awaited from the timer's active callback; use request_adjust_interval().",
@@ -800,6 +2700,8 @@ impl Timer {
     }
 
     /// Requests an interval adjustment for the current run.
+    ///
+    /// Rejects the same timers as [`Timer::adjust_interval`], which delegates here.
     pub async fn request_adjust_interval(&self, new_interval: Duration) -> Result<(), TimerError> {
         if new_interval.is_zero() {
             return Err(TimerError::invalid_parameter(
@@ -811,6 +2713,18 @@ impl Timer {
             .active_run_id()
             .await
             .ok_or_else(TimerError::not_running)?;
+        if let Some(config) = self.inner.last_config.lock().await.as_ref() {
+            if !config.union_schedules.is_empty() {
+                return Err(TimerError::invalid_parameter(
+                    "Union timers derive their fire times from their schedules.",
+                ));
+            }
+            if config.cron.is_some() {
+                return Err(TimerError::invalid_parameter(
+                    "Cron timers derive their fire times from their expression.",
+                ));
+            }
+        }
         *self.inner.interval.lock().await = new_interval;
         self.send_command(TimerCommand::SetInterval(new_interval))
             .await;
@@ -823,7 +2737,7 @@ impl Timer {
         );
 
         #[cfg(feature = "logging")]
-        debug!("Timer interval adjusted.");
+        debug!("{} interval adjusted.", self.log_name().await);
 
         Ok(())
     }
@@ -852,7 +2766,62 @@ impl Timer {
 
     /// Gets the timer's statistics for the current or most recent run.
     pub async fn get_statistics(&self) -> TimerStatistics {
-        self.inner.statistics.lock().await.clone()
+        let statistics = self.inner.lock_statistics().await.clone();
+        match self.inner.stats_level() {
+            StatsLevel::Counters => TimerStatistics {
+                elapsed_time: statistics.elapsed_time,
+                ..self.inner.counters.snapshot()
+            },
+            StatsLevel::Off | StatsLevel::Full => statistics,
+        }
+    }
+
+    /// Captures the current statistics so a later window can be measured with [`Timer::stats_since`].
+    pub async fn checkpoint(&self) -> StatisticsCheckpoint {
+        StatisticsCheckpoint {
+            statistics_epoch: self.inner.statistics_epoch.load(Ordering::SeqCst),
+            statistics: self.get_statistics().await,
+            taken_at: self.inner.runtime.now(),
+        }
+    }
+
+    /// Returns the statistics accumulated since `checkpoint` was taken.
+    ///
+    /// Statistics reset when a new run starts and when they are taken with
+    /// [`Timer::take_statistics`]; if they reset since the checkpoint, the
+    /// delta covers what was recorded after the latest reset. Runs started
+    /// with [`Timer::restart_preserving_stats`] keep measuring from the
+    /// checkpoint.
+    pub async fn stats_since(&self, checkpoint: &StatisticsCheckpoint) -> StatisticsDelta {
+        let current = self.get_statistics().await;
+        let same_statistics =
+            self.inner.statistics_epoch.load(Ordering::SeqCst) == checkpoint.statistics_epoch;
+        let base = if same_statistics {
+            &checkpoint.statistics
+        } else {
+            &TimerStatistics::default()
+        };
+
+        StatisticsDelta {
+            execution_count: current.execution_count.saturating_sub(base.execution_count),
+            successful_executions: current
+                .successful_executions
+                .saturating_sub(base.successful_executions),
+            failed_executions: current
+                .failed_executions
+                .saturating_sub(base.failed_executions),
+            skipped_executions: current
+                .skipped_executions
+                .saturating_sub(base.skipped_executions),
+            total_execution_duration: current
+                .total_execution_duration
+                .saturating_sub(base.total_execution_duration),
+            elapsed: self
+                .inner
+                .runtime
+                .now()
+                .saturating_duration_since(checkpoint.taken_at),
+        }
     }
 
     /// Gets the current state of the timer.
@@ -860,6 +2829,133 @@ impl Timer {
         *self.inner.state.lock().await
     }
 
+    /// Returns when the timer was created, which may precede its first start.
+    pub fn created_at(&self) -> Instant {
+        self.inner.created_at
+    }
+
+    /// Returns the wall-clock time at which the timer was created.
+    pub fn created_at_system_time(&self) -> SystemTime {
+        self.inner.created_at_system
+    }
+
+    /// Returns how often accessing the statistics had to wait for another holder.
+    ///
+    /// This is a diagnostic for high-frequency timers: a steadily growing
+    /// count means readers and the run task compete for the statistics lock.
+    #[cfg(feature = "profiling")]
+    pub fn statistics_lock_contention(&self) -> u64 {
+        self.inner.statistics_contention.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the timer reports running but its task is still parked from a pause.
+    ///
+    /// A resume request is delivered asynchronously, so this is briefly `true`
+    /// right after [`Timer::resume`]. If it stays `true`, the resume signal
+    /// was never observed by the run task.
+    pub async fn pending_resume(&self) -> bool {
+        self.inner.parked_while_paused.load(Ordering::SeqCst)
+            && *self.inner.state.lock().await == TimerState::Running
+    }
+
+    /// Returns how long the timer's locks have been held, per lock.
+    ///
+    /// Covers the locks taken on every tick and accumulates across runs.
+    #[cfg(feature = "profiling")]
+    pub fn lock_profile(&self) -> LockProfile {
+        self.inner.lock_profiler.snapshot()
+    }
+
+    /// Returns how long ago the callback last started executing.
+    ///
+    /// Returns `None` if the callback has never executed. The value carries
+    /// over between runs, so it also reflects executions of earlier runs.
+    pub async fn since_last_execution(&self) -> Option<Duration> {
+        let last_execution = (*self.inner.last_execution.lock().await)?;
+        Some(
+            self.inner
+                .runtime
+                .now()
+                .saturating_duration_since(last_execution),
+        )
+    }
+
+    /// Returns the wall-clock time of the next scheduled execution.
+    ///
+    /// Returns `None` while the timer is paused or stopped and while a
+    /// callback is executing, since the next deadline is not known yet.
+    pub async fn next_fire_at(&self) -> Option<SystemTime> {
+        let remaining = self.time_until_next().await?;
+        Some(self.inner.runtime.system_now() + remaining)
+    }
+
+    /// Returns the time left before the next scheduled execution, clamped to zero.
+    ///
+    /// Like [`Timer::next_fire_at`], this returns `None` while the timer is
+    /// paused or stopped and while a callback is executing. After a resume
+    /// it reports the rescheduled deadline.
+    pub async fn time_until_next(&self) -> Option<Duration> {
+        if self.get_state().await != TimerState::Running {
+            return None;
+        }
+
+        let deadline = (*self.inner.next_fire.lock().await)?;
+        Some(deadline.saturating_duration_since(self.inner.runtime.now()))
+    }
+
+    /// Waits until the timer reaches `target`.
+    ///
+    /// Resolves immediately if the timer is already in `target`. Waiters only
+    /// observe the latest state, so a state that is entered and left again
+    /// before the waiter is polled can be missed.
+    pub async fn wait_for_state(&self, target: TimerState) {
+        let mut receiver = self.inner.state_tx.subscribe();
+        // The sender lives in `TimerInner`, which `self` keeps alive.
+        let _ = receiver.wait_for(|state| *state == target).await;
+    }
+
+    /// Returns the current statistics and resets them in a single step.
+    ///
+    /// Counters, durations, and the last error start over from zero, so every
+    /// execution is reported by exactly one call. `elapsed_time` keeps tracking
+    /// the current run. Outcomes of runs that were taken from only include what
+    /// happened after the most recent call.
+    pub async fn take_statistics(&self) -> TimerStatistics {
+        let mut statistics = self.inner.lock_statistics().await;
+        let elapsed_time = statistics.elapsed_time;
+        let taken = std::mem::take(&mut *statistics);
+        statistics.elapsed_time = elapsed_time;
+        self.inner.statistics_epoch.fetch_add(1, Ordering::SeqCst);
+        match self.inner.stats_level() {
+            StatsLevel::Counters => TimerStatistics {
+                elapsed_time,
+                ..self.inner.counters.take()
+            },
+            StatsLevel::Off | StatsLevel::Full => taken,
+        }
+    }
+
+    /// Reads the timer state without awaiting.
+    ///
+    /// This is intended for synchronous contexts such as `Drop` implementations
+    /// or logging paths. It returns `None` only when the state cannot be observed.
+    pub fn try_state(&self) -> Option<TimerState> {
+        TimerState::from_u8(self.inner.state_mirror.load(Ordering::SeqCst))
+    }
+
+    /// Reports whether the background task for the current run is still alive.
+    ///
+    /// This complements [`Timer::get_state`]: a task that died without
+    /// finishing its run can leave the state reporting `Running`.
+    pub async fn is_task_alive(&self) -> bool {
+        self.inner
+            .handle
+            .lock()
+            .await
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+
     /// Gets the timer interval for the current or next run.
     pub async fn get_interval(&self) -> Duration {
         *self.inner.interval.lock().await
@@ -870,9 +2966,42 @@ impl Timer {
         *self.inner.expiration_count.lock().await
     }
 
+    /// Reports whether the current or most recent one-shot run fired.
+    ///
+    /// Returns `None` for recurring runs and for timers that were never started.
+    pub async fn one_shot_outcome(&self) -> Option<OneShotOutcome> {
+        let recurring = self.inner.last_config.lock().await.as_ref()?.recurring;
+        if recurring {
+            return None;
+        }
+
+        if self.inner.fired.load(Ordering::SeqCst) {
+            Some(OneShotOutcome::Fired)
+        } else if self.active_run_id().await.is_some() {
+            Some(OneShotOutcome::Pending)
+        } else {
+            Some(OneShotOutcome::Cancelled)
+        }
+    }
+
     /// Gets the most recent callback error observed for the current or most recent run.
     pub async fn get_last_error(&self) -> Option<TimerError> {
-        self.inner.statistics.lock().await.last_error.clone()
+        self.inner.lock_statistics().await.last_error.clone()
+    }
+
+    /// Returns the result of the most recent callback execution.
+    ///
+    /// The value is updated after every tick, including any retries, and is
+    /// `None` until the current or most recent run has executed once.
+    pub async fn last_result(&self) -> Option<Result<(), TimerError>> {
+        self.inner.last_result.lock().await.clone()
+    }
+
+    /// Returns the scheduling drift distribution for the current or most recent run.
+    ///
+    /// Executions fired early through [`Timer::trigger_now`] are not recorded.
+    pub async fn drift_histogram(&self) -> DriftHistogram {
+        self.inner.drift_histogram.lock().await.clone()
     }
 
     /// Returns the metadata currently associated with the timer.
@@ -885,6 +3014,15 @@ impl Timer {
         self.inner.metadata.lock().await.label.clone()
     }
 
+    /// Names the timer in log messages, using its label when it has one.
+    #[cfg(feature = "logging")]
+    async fn log_name(&self) -> String {
+        match self.label().await {
+            Some(label) => format!("Timer '{label}'"),
+            None => "Timer".to_owned(),
+        }
+    }
+
     /// Sets the timer label used for diagnostics and registry introspection.
     pub async fn set_label(&self, label: impl Into<String>) {
         self.inner.metadata.lock().await.label = Some(label.into());
@@ -900,6 +3038,14 @@ impl Timer {
             .insert(key.into(), value.into());
     }
 
+    /// Replaces the timer's label and tags.
+    ///
+    /// Metadata set before a run starts is reported in its
+    /// [`TimerEvent::Started`] event; it always appears in snapshots.
+    pub async fn set_metadata(&self, metadata: TimerMetadata) {
+        *self.inner.metadata.lock().await = metadata;
+    }
+
     /// Captures a snapshot of the timer's current observable state.
     pub async fn snapshot(&self) -> TimerSnapshot {
         TimerSnapshot {
@@ -909,17 +3055,97 @@ impl Timer {
             statistics: self.get_statistics().await,
             last_outcome: self.last_outcome().await,
             metadata: self.metadata().await,
+            created_at: self.inner.created_at,
         }
     }
 
+    /// Returns the totals of custom metrics recorded with
+    /// [`TimerContext::record_metric`] during the current or most recent run.
+    ///
+    /// Totals are kept apart from [`TimerStatistics`] so the statistics stay
+    /// comparable with `Eq`.
+    pub fn custom_totals(&self) -> BTreeMap<String, f64> {
+        self.inner
+            .custom_totals
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    /// Returns the recorded execution history, oldest first.
+    ///
+    /// History is only recorded for timers built with
+    /// [`TimerBuilder::with_history_capacity`].
+    pub async fn execution_history(&self) -> Vec<ExecutionRecord> {
+        self.inner.history.lock().await.iter().copied().collect()
+    }
+
     /// Gets the most recent completed run outcome.
     pub async fn last_outcome(&self) -> Option<TimerOutcome> {
         self.inner.last_outcome.lock().await.clone()
     }
 
+    /// Registers a callback that runs once whenever a run ends, for any reason.
+    ///
+    /// The callback runs after the run has stopped but before its outcome is
+    /// delivered to completion subscribers and `Finished` events. Cancelled runs
+    /// invoke it from the cancelling task on a best-effort basis. The callback
+    /// receives a [`TimerContext`] for the finished run, and errors it returns
+    /// are ignored.
+    pub async fn on_stop<F>(&self, callback: F)
+    where
+        F: TimerCallback + 'static,
+    {
+        *self.inner.stop_hook.lock().await = Some(Arc::new(callback));
+    }
+
+    /// Registers a callback that runs once whenever a run completes on its own.
+    ///
+    /// A run completes when a one-shot timer fires or a recurring timer
+    /// reaches its expiration count, so the outcome reason is
+    /// [`TimerFinishReason::Completed`]. Runs that are stopped, cancelled, or
+    /// replaced do not invoke it; use [`Timer::on_stop`] to react to every
+    /// ending and [`Timer::last_outcome`] inside that hook to read the reason.
+    /// The callback runs before the [`Timer::on_stop`] hook with a
+    /// [`TimerContext`] for the finished run, and errors it returns are ignored.
+    pub async fn on_complete<F>(&self, callback: F)
+    where
+        F: TimerCallback + 'static,
+    {
+        *self.inner.complete_hook.lock().await = Some(Arc::new(callback));
+    }
+
+    /// Registers a handler invoked with every error a callback attempt returns.
+    ///
+    /// The handler runs before any retry of the failed attempt, including for
+    /// timeouts and panics. It replaces any previously registered handler.
+    pub async fn set_error_handler<F>(&self, handler: F)
+    where
+        F: Fn(&TimerError) + Send + Sync + 'static,
+    {
+        *self.inner.error_handler.lock().await = Some(Arc::new(handler));
+    }
+
     /// Enables or disables broadcast event emission for future runtime events.
     pub fn set_events_enabled(&self, enabled: bool) {
         self.inner.events_enabled.store(enabled, Ordering::SeqCst);
+        self.inner.events_drained.notify_waiters();
+    }
+
+    pub(crate) fn set_registry_id(&self, id: u64) {
+        self.inner.registry_id.store(id, Ordering::SeqCst);
+    }
+
+    pub(crate) async fn attach_registry(&self, registry: Weak<RegistryInner>) {
+        let jitter_rng = registry
+            .upgrade()
+            .and_then(|registry| registry.jitter_rng.clone());
+        *self
+            .inner
+            .jitter_rng
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = jitter_rng;
+        *self.inner.registry.lock().await = Some(registry);
     }
 
     async fn start_internal<F>(
@@ -931,51 +3157,18 @@ impl Timer {
     where
         F: TimerCallback + 'static,
     {
-        if config.interval.is_zero() && config.start_deadline.is_none() {
-            return Err(TimerError::invalid_parameter(
-                "Interval must be greater than zero.",
-            ));
-        }
-
-        if config.recurring && matches!(config.expiration_count, Some(0)) {
-            return Err(TimerError::invalid_parameter(
-                "Expiration count must be greater than zero.",
-            ));
-        }
-
-        if config.initial_delay.is_some_and(|delay| delay.is_zero()) {
-            return Err(TimerError::invalid_parameter(
-                "Initial delay must be greater than zero.",
-            ));
-        }
-
-        if config.jitter.is_some_and(|jitter| jitter.is_zero()) {
-            return Err(TimerError::invalid_parameter(
-                "Jitter must be greater than zero.",
-            ));
-        }
-
-        if config
-            .callback_timeout
-            .is_some_and(|timeout| timeout.is_zero())
-        {
-            return Err(TimerError::invalid_parameter(
-                "Callback timeout must be greater than zero.",
-            ));
-        }
+        self.start_shared(config, Arc::new(callback), start_paused, false)
+            .await
+    }
 
-        if config.retry_policy.is_some_and(|policy| {
-            matches!(
-                policy.backoff(),
-                RetryBackoff::Fixed(duration)
-                    | RetryBackoff::Linear(duration)
-                    | RetryBackoff::Exponential(duration) if duration.is_zero()
-            )
-        }) {
-            return Err(TimerError::invalid_parameter(
-                "Retry backoff must be greater than zero.",
-            ));
-        }
+    async fn start_shared(
+        &self,
+        config: RunConfig,
+        callback: Arc<dyn TimerCallback>,
+        start_paused: bool,
+        preserve_statistics: bool,
+    ) -> Result<u64, TimerError> {
+        config.validate()?;
 
         self.ensure_not_reentrant(
             "starting a new run from the timer's active callback is not supported; spawn a separate task instead.",
@@ -987,16 +3180,45 @@ impl Timer {
         let (tx, rx) = mpsc::unbounded_channel();
 
         {
-            *self.inner.state.lock().await = if start_paused {
-                TimerState::Paused
-            } else {
-                TimerState::Running
-            };
+            self.inner
+                .set_state(if start_paused {
+                    TimerState::Paused
+                } else {
+                    TimerState::Running
+                })
+                .await;
             *self.inner.command_tx.lock().await = Some(tx);
+            *self.inner.run_token.lock().await = CancellationToken::new();
+            self.inner
+                .parked_while_paused
+                .store(false, Ordering::SeqCst);
+            self.inner.paused_nanos.store(0, Ordering::SeqCst);
+            self.inner.fired.store(false, Ordering::SeqCst);
+            self.inner
+                .recurring
+                .store(config.recurring, Ordering::SeqCst);
+            *self.inner.retry_policy.lock().await = config.retry_policy;
             *self.inner.interval.lock().await = config.interval;
             *self.inner.expiration_count.lock().await = config.expiration_count;
             *self.inner.metadata.lock().await = config.metadata.clone();
-            *self.inner.statistics.lock().await = TimerStatistics::default();
+            if !preserve_statistics {
+                *self.inner.lock_statistics().await = TimerStatistics::default();
+                self.inner
+                    .custom_totals
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clear();
+                let _ = self.inner.counters.take();
+                self.inner.statistics_epoch.fetch_add(1, Ordering::SeqCst);
+            }
+            self.inner
+                .stats_level
+                .store(config.stats_level.to_u8(), Ordering::SeqCst);
+            self.inner.history.lock().await.clear();
+            *self.inner.last_result.lock().await = None;
+            *self.inner.drift_histogram.lock().await = DriftHistogram::default();
+            *self.inner.last_config.lock().await = Some(config.clone());
+            *self.inner.last_callback.lock().await = Some(Arc::clone(&callback));
             *self.inner.last_outcome.lock().await = None;
             self.inner.completion_tx.send_replace(None);
         }
@@ -1013,11 +3235,13 @@ impl Timer {
             },
         );
 
+        let finalizer = Finalizer(self.inner.finalizer.lock().await.take());
         let inner = Arc::clone(&self.inner);
         let handle = self.inner.runtime.spawn(async move {
+            let _finalizer = finalizer;
             let scoped_inner = Arc::clone(&inner);
             runtime::with_run_context(&scoped_inner, run_id, async move {
-                runtime::run_timer(inner, run_id, config, callback, rx).await;
+                runtime::run_timer(inner, run_id, config, SharedCallback(callback), rx).await;
             })
             .await;
         });
@@ -1025,7 +3249,7 @@ impl Timer {
         *self.inner.handle.lock().await = Some(handle);
 
         #[cfg(feature = "logging")]
-        debug!("Timer started.");
+        debug!("{} started.", self.log_name().await);
 
         Ok(run_id)
     }
@@ -1061,8 +3285,9 @@ impl Timer {
             .ok_or_else(TimerError::not_running)?;
 
         let _ = self.inner.command_tx.lock().await.take();
+        self.inner.run_token.lock().await.cancel();
         let handle = self.inner.handle.lock().await.take();
-        *self.inner.state.lock().await = TimerState::Stopped;
+        self.inner.set_state(TimerState::Stopped).await;
 
         if let Some(handle) = handle {
             handle.abort();
@@ -1101,40 +3326,39 @@ impl Timer {
 }
 
 impl TimerBuilder {
-    /// Creates a builder for a one-time timer.
-    pub fn once(delay: Duration) -> Self {
+    fn new(kind: TimerKind) -> Self {
         Self {
-            kind: TimerKind::Once(delay),
+            kind,
             callback_timeout: None,
+            soft_timeout: None,
+            history_capacity: 0,
+            pause_on_full_events: false,
             retry_policy: None,
+            leadership: None,
+            enabled_flag: None,
+            finalizer: None,
+            priority: Timer::DEFAULT_PRIORITY,
+            stats_level: StatsLevel::Full,
+            event_capacity: TIMER_EVENT_BUFFER,
             start_paused: false,
             events_enabled: true,
             metadata: TimerMetadata::default(),
         }
     }
 
+    /// Creates a builder for a one-time timer.
+    pub fn once(delay: Duration) -> Self {
+        Self::new(TimerKind::Once(delay))
+    }
+
     /// Creates a builder for a one-time timer at a deadline.
     pub fn at(deadline: Instant) -> Self {
-        Self {
-            kind: TimerKind::At(deadline),
-            callback_timeout: None,
-            retry_policy: None,
-            start_paused: false,
-            events_enabled: true,
-            metadata: TimerMetadata::default(),
-        }
+        Self::new(TimerKind::At(deadline))
     }
 
     /// Creates a builder for a recurring schedule.
     pub fn recurring(schedule: RecurringSchedule) -> Self {
-        Self {
-            kind: TimerKind::Recurring(schedule),
-            callback_timeout: None,
-            retry_policy: None,
-            start_paused: false,
-            events_enabled: true,
-            metadata: TimerMetadata::default(),
-        }
+        Self::new(TimerKind::Recurring(schedule))
     }
 
     /// Sets a timeout for each callback execution.
@@ -1143,6 +3367,20 @@ impl TimerBuilder {
         self
     }
 
+    /// Flags callback executions that run longer than `soft_timeout` without cancelling them.
+    pub fn soft_timeout(mut self, soft_timeout: Duration) -> Self {
+        self.soft_timeout = Some(soft_timeout);
+        self
+    }
+
+    /// Cancels callback executions that run longer than `hard_timeout`.
+    ///
+    /// This is an alias for [`TimerBuilder::callback_timeout`] that reads naturally
+    /// alongside [`TimerBuilder::soft_timeout`].
+    pub fn hard_timeout(self, hard_timeout: Duration) -> Self {
+        self.callback_timeout(hard_timeout)
+    }
+
     /// Retries failed callback executions according to the provided policy.
     pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
         self.retry_policy = Some(retry_policy);
@@ -1185,12 +3423,81 @@ impl TimerBuilder {
         self
     }
 
+    /// Records the most recent `capacity` executions in a preallocated history buffer.
+    pub fn with_history_capacity(mut self, capacity: usize) -> Self {
+        self.history_capacity = capacity;
+        self
+    }
+
+    /// Only executes the callback while `leadership` reports this instance as the leader.
+    ///
+    /// In multi-instance deployments every instance keeps ticking on schedule,
+    /// but followers skip the callback. The gate is consulted once per tick
+    /// and skips are counted in [`TimerStatistics::follower_skips`].
+    pub fn leadership(mut self, leadership: Arc<dyn Fn() -> bool + Send + Sync>) -> Self {
+        self.leadership = Some(Leadership(leadership));
+        self
+    }
+
+    /// Only executes the callback while `flag` is `true`.
+    ///
+    /// Unlike pausing, the schedule keeps advancing while the flag is
+    /// `false`; the flag is read once per tick without locking, and skipped
+    /// ticks are counted in [`TimerStatistics::skipped_executions`].
+    pub fn with_enabled_flag(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.enabled_flag = Some(flag);
+        self
+    }
+
     /// Starts the timer in the paused state.
     pub fn paused_start(mut self) -> Self {
         self.start_paused = true;
         self
     }
 
+    /// Holds back ticks while the event buffer is full instead of dropping events.
+    ///
+    /// Each due tick waits until the slowest subscriber has left room for the
+    /// events a tick emits. Lifecycle events emitted by control methods are not
+    /// held back.
+    pub fn pause_on_full_events(mut self, enabled: bool) -> Self {
+        self.pause_on_full_events = enabled;
+        self
+    }
+
+    /// Runs `finalizer` when the timer's first background task exits for any reason.
+    ///
+    /// The finalizer is synchronous and best-effort: it runs when the task
+    /// completes, is stopped or cancelled, or is aborted, but not if the
+    /// process exits first. Use it to release resources the callback acquired.
+    /// It runs at most once per timer, so runs started later by restarting
+    /// the timer exit without it.
+    pub fn finalizer(mut self, finalizer: impl FnOnce() + Send + 'static) -> Self {
+        self.finalizer = Some(std::sync::Mutex::new(Box::new(finalizer)));
+        self
+    }
+
+    /// Sets the dispatch priority; see [`Timer::set_priority`].
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets how much statistics the timer records on every tick.
+    pub fn stats_level(mut self, stats_level: StatsLevel) -> Self {
+        self.stats_level = stats_level;
+        self
+    }
+
+    /// Sets how many events the broadcast channel buffers for slow subscribers.
+    ///
+    /// Defaults to 64. Subscribers that fall further behind skip the oldest
+    /// events and report the count through [`TimerEvents::lagged_events`].
+    pub fn event_capacity(mut self, capacity: usize) -> Self {
+        self.event_capacity = capacity;
+        self
+    }
+
     /// Disables broadcast event emission for the timer.
     pub fn with_events_disabled(mut self) -> Self {
         self.events_enabled = false;
@@ -1209,88 +3516,102 @@ impl TimerBuilder {
         self
     }
 
+    /// Validates the configuration and returns a stopped timer that carries it.
+    ///
+    /// Every problem that would make [`TimerBuilder::start`] fail is reported
+    /// here, before any task is spawned. Start the timer with
+    /// [`Timer::restart_with`]. [`TimerBuilder::paused_start`] only applies to
+    /// [`TimerBuilder::start`].
+    pub async fn build(self) -> Result<Timer, TimerError> {
+        let (timer, config, _) = self.prepare().await?;
+        *timer.inner.interval.lock().await = config.interval;
+        *timer.inner.expiration_count.lock().await = config.expiration_count;
+        *timer.inner.metadata.lock().await = config.metadata.clone();
+        *timer.inner.last_config.lock().await = Some(config);
+        Ok(timer)
+    }
+
     /// Starts the configured timer and returns the handle.
     pub async fn start<F>(self, callback: F) -> Result<Timer, TimerError>
     where
         F: TimerCallback + 'static,
     {
+        let (timer, config, start_paused) = self.prepare().await?;
+        if start_paused {
+            timer.inner.set_state(TimerState::Paused).await;
+        }
+
+        let _ = timer.start_internal(config, callback, start_paused).await?;
+        Ok(timer)
+    }
+
+    async fn prepare(self) -> Result<(Timer, RunConfig, bool), TimerError> {
         let Self {
             kind,
             callback_timeout,
+            soft_timeout,
+            history_capacity,
+            pause_on_full_events,
             retry_policy,
+            leadership,
+            enabled_flag,
+            finalizer,
+            priority,
+            stats_level,
+            event_capacity,
             start_paused,
             events_enabled,
             metadata,
         } = self;
 
-        let timer = Timer::new_with_runtime(driver::RuntimeHandle::default(), events_enabled);
-        if start_paused {
-            *timer.inner.state.lock().await = TimerState::Paused;
-        }
-
-        match kind {
-            TimerKind::Once(delay) => {
-                let _ = timer
-                    .start_internal(
-                        RunConfig {
-                            interval: delay,
-                            start_deadline: None,
-                            initial_delay: None,
-                            jitter: None,
-                            callback_timeout,
-                            retry_policy,
-                            recurring: false,
-                            cadence: RecurringCadence::FixedDelay,
-                            expiration_count: None,
-                            metadata: metadata.clone(),
-                        },
-                        callback,
-                        start_paused,
-                    )
-                    .await?;
-            }
-            TimerKind::At(deadline) => {
-                let now = timer.inner.runtime.now();
-                let _ = timer
-                    .start_internal(
-                        RunConfig {
-                            interval: deadline.saturating_duration_since(now),
-                            start_deadline: Some(deadline),
-                            initial_delay: None,
-                            jitter: None,
-                            callback_timeout,
-                            retry_policy,
-                            recurring: false,
-                            cadence: RecurringCadence::FixedDelay,
-                            expiration_count: None,
-                            metadata: metadata.clone(),
-                        },
-                        callback,
-                        start_paused,
-                    )
-                    .await?;
-            }
-            TimerKind::Recurring(schedule) => {
-                let _ = timer
-                    .start_internal(
-                        RunConfig {
-                            interval: schedule.interval,
-                            start_deadline: None,
-                            initial_delay: schedule.initial_delay,
-                            jitter: schedule.jitter,
-                            callback_timeout,
-                            retry_policy,
-                            recurring: true,
-                            cadence: schedule.cadence,
-                            expiration_count: schedule.expiration_count,
-                            metadata,
-                        },
-                        callback,
-                        start_paused,
-                    )
-                    .await?;
-            }
+        if event_capacity == 0 {
+            return Err(TimerError::invalid_parameter(
+                "Event capacity must be greater than zero.",
+            ));
+        }
+
+        let timer = Timer::new_with_event_capacity(
+            driver::RuntimeHandle::default(),
+            events_enabled,
+            event_capacity,
+        );
+        timer.set_priority(priority);
+        let mut config = match kind {
+            TimerKind::Once(delay) => RunConfig::once(delay, metadata),
+            TimerKind::At(deadline) => RunConfig::at(deadline, timer.inner.runtime.now(), metadata),
+            TimerKind::Recurring(schedule) => RunConfig::recurring(schedule, metadata),
+        };
+        config.callback_timeout = callback_timeout;
+        config.soft_timeout = soft_timeout;
+        config.history_capacity = history_capacity;
+        config.pause_on_full_events = pause_on_full_events;
+        config.retry_policy = retry_policy;
+        config.leadership = leadership;
+        config.enabled_flag = enabled_flag;
+        config.stats_level = stats_level;
+        config.validate()?;
+
+        if history_capacity > 0 {
+            *timer.inner.history.lock().await = VecDeque::with_capacity(history_capacity);
+        }
+        *timer.inner.finalizer.lock().await = finalizer.map(|finalizer| {
+            finalizer
+                .into_inner()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+        });
+        Ok((timer, config, start_paused))
+    }
+}
+
+/// Synchronous cleanup that runs when a run's background task exits.
+///
+/// The closure runs from `Drop`, so it also runs when the task is aborted.
+struct Finalizer(Option<Box<dyn FnOnce() + Send>>);
+
+impl Drop for Finalizer {
+    fn drop(&mut self) {
+        if let Some(finalizer) = self.0.take() {
+            finalizer();
         }
-        Ok(timer)
     }
 }
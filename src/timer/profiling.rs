@@ -0,0 +1,166 @@
+use tokio::sync::{Mutex, MutexGuard};
+
+#[cfg(feature = "profiling")]
+use std::ops::{Deref, DerefMut};
+#[cfg(feature = "profiling")]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "profiling")]
+use std::time::{Duration, Instant};
+
+/// Timer locks whose hold time is profiled with the `profiling` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LockSite {
+    State,
+    Statistics,
+    History,
+    DriftHistogram,
+    LastResult,
+    LastExecution,
+    NextFire,
+}
+
+#[cfg(feature = "profiling")]
+const LOCK_SITES: usize = 7;
+
+/// Total time the run loop spent holding each profiled timer lock.
+///
+/// Times are measured on the real clock, so they are meaningful even when
+/// Tokio time is paused.
+#[cfg(feature = "profiling")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LockProfile {
+    /// Time spent holding the state lock.
+    pub state: Duration,
+    /// Time spent holding the statistics lock.
+    pub statistics: Duration,
+    /// Time spent holding the execution history lock.
+    pub history: Duration,
+    /// Time spent holding the drift histogram lock.
+    pub drift_histogram: Duration,
+    /// Time spent holding the last-result lock.
+    pub last_result: Duration,
+    /// Time spent holding the last-execution lock.
+    pub last_execution: Duration,
+    /// Time spent holding the next-fire lock.
+    pub next_fire: Duration,
+}
+
+#[cfg(feature = "profiling")]
+impl LockProfile {
+    /// Returns the combined hold time across all profiled locks.
+    pub fn total(&self) -> Duration {
+        self.state
+            + self.statistics
+            + self.history
+            + self.drift_histogram
+            + self.last_result
+            + self.last_execution
+            + self.next_fire
+    }
+}
+
+/// Per-timer accumulator of lock hold times, in nanoseconds.
+#[cfg(feature = "profiling")]
+#[derive(Default)]
+pub(crate) struct LockProfiler {
+    held_nanos: [AtomicU64; LOCK_SITES],
+}
+
+#[cfg(feature = "profiling")]
+impl LockProfiler {
+    fn slot(&self, site: LockSite) -> &AtomicU64 {
+        &self.held_nanos[site as usize]
+    }
+
+    pub(crate) fn snapshot(&self) -> LockProfile {
+        let held = |site| Duration::from_nanos(self.slot(site).load(Ordering::Relaxed));
+        LockProfile {
+            state: held(LockSite::State),
+            statistics: held(LockSite::Statistics),
+            history: held(LockSite::History),
+            drift_histogram: held(LockSite::DriftHistogram),
+            last_result: held(LockSite::LastResult),
+            last_execution: held(LockSite::LastExecution),
+            next_fire: held(LockSite::NextFire),
+        }
+    }
+
+    pub(crate) fn guard<'a, T>(
+        &'a self,
+        site: LockSite,
+        guard: MutexGuard<'a, T>,
+    ) -> ProfiledGuard<'a, T> {
+        ProfiledGuard {
+            guard,
+            acquired_at: Instant::now(),
+            slot: self.slot(site),
+        }
+    }
+}
+
+/// Mutex guard that adds its hold time to a [`LockProfiler`] slot when dropped.
+#[cfg(feature = "profiling")]
+pub(crate) struct ProfiledGuard<'a, T> {
+    guard: MutexGuard<'a, T>,
+    acquired_at: Instant,
+    slot: &'a AtomicU64,
+}
+
+#[cfg(feature = "profiling")]
+impl<T> Deref for ProfiledGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.guard
+    }
+}
+
+#[cfg(feature = "profiling")]
+impl<T> DerefMut for ProfiledGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.guard
+    }
+}
+
+#[cfg(feature = "profiling")]
+impl<T> Drop for ProfiledGuard<'_, T> {
+    fn drop(&mut self) {
+        let held = u64::try_from(self.acquired_at.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        self.slot.fetch_add(held, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "profiling")]
+pub(crate) type Profiled<'a, T> = ProfiledGuard<'a, T>;
+#[cfg(not(feature = "profiling"))]
+pub(crate) type Profiled<'a, T> = MutexGuard<'a, T>;
+
+impl super::TimerInner {
+    /// Locks `mutex`, attributing the hold time to `site` when profiling.
+    pub(crate) async fn lock_profiled<'a, T>(
+        &'a self,
+        mutex: &'a Mutex<T>,
+        site: LockSite,
+    ) -> Profiled<'a, T> {
+        self.profile_guard(site, mutex.lock().await)
+    }
+
+    #[cfg(feature = "profiling")]
+    pub(crate) fn profile_guard<'a, T>(
+        &'a self,
+        site: LockSite,
+        guard: MutexGuard<'a, T>,
+    ) -> Profiled<'a, T> {
+        self.lock_profiler.guard(site, guard)
+    }
+
+    #[cfg(not(feature = "profiling"))]
+    pub(crate) fn profile_guard<'a, T>(
+        &'a self,
+        _site: LockSite,
+        guard: MutexGuard<'a, T>,
+    ) -> Profiled<'a, T> {
+        guard
+    }
+}
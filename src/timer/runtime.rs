@@ -1,17 +1,34 @@
+use std::any::Any;
 use std::future::Future;
-use std::sync::Arc;
+use std::panic::AssertUnwindSafe;
+use std::sync::{atomic::Ordering, Arc, Weak};
+use std::task::Poll;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, OwnedSemaphorePermit};
 use tokio::time;
 use tokio::time::Instant;
 
 #[cfg(feature = "logging")]
-use log::error;
+use log::{error, warn};
 
+use super::driver::SleepFuture;
+use super::profiling::LockSite;
 use super::{
-    RecurringCadence, RetryPolicy, RunConfig, TimerCallback, TimerCommand, TimerEvent,
-    TimerFinishReason, TimerInner, TimerOutcome, TimerState,
+    window_contains, CronSchedule, ExecutionRecord, PanicPolicy, RecurringCadence, RetryPolicy,
+    RunConfig, StatsLevel, TimeOfDay, Timer, TimerCallback, TimerCommand, TimerContext, TimerEvent,
+    TimerFinishReason, TimerInner, TimerOutcome, TimerState, TimerStatistics, EVENTS_PER_TICK,
 };
+use crate::errors::TimerError;
+use crate::registry::{DispatchTurn, TimerRegistry};
+
+/// Deadline offset used once a fixed-rate schedule outruns the clock's range.
+const FAR_FUTURE: Duration = Duration::from_secs(86_400 * 365 * 30);
+/// Wall and monotonic clocks can disagree slightly, so a cron tick that wakes
+/// just before its minute is not scheduled again for that same minute.
+const CRON_CLOCK_SKEW: Duration = Duration::from_secs(1);
+
+/// Union fires closer together than this are collapsed into a single execution.
+const UNION_COALESCE_WINDOW: Duration = Duration::from_millis(10);
 
 tokio::task_local! {
     static ACTIVE_RUN_CONTEXT: ActiveRunContext;
@@ -23,15 +40,6 @@ struct ActiveRunContext {
     run_id: u64,
 }
 
-struct RunSnapshot {
-    run_id: u64,
-    started_at: Instant,
-    tick_count: usize,
-    success_count: usize,
-    failure_count: usize,
-    last_error: Option<crate::errors::TimerError>,
-}
-
 pub(super) async fn with_run_context<F>(inner: &Arc<TimerInner>, run_id: u64, future: F)
 where
     F: Future<Output = ()>,
@@ -66,117 +74,153 @@ pub(super) async fn run_timer<F>(
     F: TimerCallback + 'static,
 {
     let started_at = inner.runtime.now();
-    let first_delay = first_sleep_delay(&inner, &config);
+    let mut union_clock = UnionClock::from_config(started_at, &config);
+    let first_delay = first_sleep_delay(&inner, &config, &mut union_clock);
     let mut tick_count = 0usize;
-    let mut success_count = 0usize;
-    let mut failure_count = 0usize;
+    let mut counted_ticks = 0usize;
+    let mut last_fired_at: Option<Instant> = None;
     let mut current_interval = config.interval;
     let mut next_sleep = first_delay;
-    let mut next_deadline = config.recurring.then_some(started_at + first_delay);
-    let mut last_error = None;
+    let mut catch_up_ticks = 0usize;
+    let mut fire_now_pending = false;
+    let mut start_deadline = config.start_deadline;
+    let mut fixed_rate_clock = config
+        .recurring
+        .then(|| FixedRateClock::new(started_at + first_delay, current_interval));
 
     loop {
-        match wait_while_paused(&inner, &mut rx, &mut current_interval).await {
-            RunControl::Continue => {}
-            RunControl::Finish(reason) => {
-                let outcome = snapshot_outcome(
-                    &inner,
-                    reason,
-                    RunSnapshot {
-                        run_id,
-                        started_at,
-                        tick_count,
-                        success_count,
-                        failure_count,
-                        last_error: last_error.clone(),
-                    },
-                )
-                .await;
-                finish_run(&inner, outcome).await;
-                return;
-            }
+        if let RunControl::Finish(reason) = wait_while_paused(
+            &inner,
+            &mut rx,
+            &mut current_interval,
+            &mut fire_now_pending,
+        )
+        .await
+        {
+            finish_with(&inner, run_id, started_at, reason).await;
+            return;
+        }
+        if let RunControl::Finish(reason) = fire_pending(
+            &inner,
+            run_id,
+            started_at,
+            &mut tick_count,
+            &mut fire_now_pending,
+            &config,
+            &callback,
+        )
+        .await
+        {
+            finish_with(&inner, run_id, started_at, reason).await;
+            return;
         }
 
-        let sleep = if !config.recurring {
-            if let Some(deadline) = config.start_deadline {
-                inner.runtime.sleep_until(deadline)
-            } else {
-                inner.runtime.sleep(next_sleep)
-            }
-        } else {
-            inner.runtime.sleep(next_sleep)
+        let mut sleep_deadline = match start_deadline {
+            Some(deadline) if !config.recurring => deadline,
+            _ => deadline_after(&inner, next_sleep),
         };
+        let sleep = arm_sleep(&inner, &config, sleep_deadline).await;
         tokio::pin!(sleep);
 
-        loop {
+        let triggered = loop {
             tokio::select! {
-                _ = &mut sleep => break,
+                _ = &mut sleep => {
+                    if current_interval < FAR_FUTURE {
+                        break false;
+                    }
+                    // The interval is effectively infinite: stay parked until it changes.
+                    sleep_deadline = deadline_after(&inner, FAR_FUTURE);
+                    sleep.set(arm_sleep(&inner, &config, sleep_deadline).await);
+                }
                 cmd = rx.recv() => match cmd {
                     Some(TimerCommand::Pause) => {
-                        *inner.state.lock().await = TimerState::Paused;
+                        inner.set_state(TimerState::Paused).await;
                         emit_event(&inner, TimerEvent::Paused { run_id });
-                        match wait_while_paused(&inner, &mut rx, &mut current_interval).await {
-                            RunControl::Continue => {
-                                reset_recurring_deadline(
+                        let remaining =
+                            sleep_deadline.saturating_duration_since(inner.runtime.now());
+                        let interval_at_pause = current_interval;
+                        let control = wait_while_paused(
+                            &inner,
+                            &mut rx,
+                            &mut current_interval,
+                            &mut fire_now_pending,
+                        )
+                        .await;
+                        match control {
+                            RunControl::Continue | RunControl::Resumed => {
+                                let resume_sleep = resume_schedule(
                                     &inner,
                                     &config,
-                                    &mut next_deadline,
+                                    &mut fixed_rate_clock,
+                                    &mut union_clock,
                                     current_interval,
+                                    InterruptedSleep {
+                                        deadline: sleep_deadline,
+                                        remaining: (current_interval == interval_at_pause)
+                                            .then_some(remaining),
+                                    },
+                                    &mut catch_up_ticks,
                                 );
-                                sleep.set(inner.runtime.sleep(current_interval));
-                            }
-                            RunControl::Finish(reason) => {
-                                let outcome = snapshot_outcome(
+                                sleep_deadline = deadline_after(&inner, resume_sleep);
+                                sleep.set(arm_sleep(&inner, &config, sleep_deadline).await);
+                                if let RunControl::Finish(reason) = fire_pending(
                                     &inner,
-                                    reason,
-                                    RunSnapshot {
-                                        run_id,
-                                        started_at,
-                                        tick_count,
-                                        success_count,
-                                        failure_count,
-                                        last_error: last_error.clone(),
-                                    },
+                                    run_id,
+                                    started_at,
+                                    &mut tick_count,
+                                    &mut fire_now_pending,
+                                    &config,
+                                    &callback,
                                 )
-                                .await;
-                                finish_run(&inner, outcome).await;
+                                .await
+                                {
+                                    finish_with(&inner, run_id, started_at, reason).await;
+                                    return;
+                                }
+                            }
+                            RunControl::Finish(reason) => {
+                                finish_with(&inner, run_id, started_at, reason).await;
                                 return;
                             }
                         }
                     }
                     Some(TimerCommand::Cancel) => {
-                        let outcome = snapshot_outcome(
+                        finish_with(
                             &inner,
+                            run_id,
+                            started_at,
                             TimerFinishReason::Cancelled,
-                            RunSnapshot {
-                                run_id,
-                                started_at,
-                                tick_count,
-                                success_count,
-                                failure_count,
-                                last_error: last_error.clone(),
-                            },
                         )
                         .await;
-                        finish_run(&inner, outcome).await;
                         return;
                     }
                     Some(TimerCommand::Resume) => {}
+                    Some(TimerCommand::TriggerNow) => break true,
+                    Some(TimerCommand::FireNow) => {
+                        fire_now_pending = true;
+                        if let RunControl::Finish(reason) = fire_pending(
+                            &inner,
+                            run_id,
+                            started_at,
+                            &mut tick_count,
+                            &mut fire_now_pending,
+                            &config,
+                            &callback,
+                        )
+                        .await
+                        {
+                            finish_with(&inner, run_id, started_at, reason).await;
+                            return;
+                        }
+                    }
                     Some(TimerCommand::Stop) | None => {
-                        let outcome = snapshot_outcome(
+                        finish_with(
                             &inner,
+                            run_id,
+                            started_at,
                             TimerFinishReason::Stopped,
-                            RunSnapshot {
-                                run_id,
-                                started_at,
-                                tick_count,
-                                success_count,
-                                failure_count,
-                                last_error: last_error.clone(),
-                            },
                         )
                         .await;
-                        finish_run(&inner, outcome).await;
                         return;
                     }
                     Some(TimerCommand::SetInterval(new_interval)) => {
@@ -184,133 +228,583 @@ pub(super) async fn run_timer<F>(
                         reset_recurring_deadline(
                             &inner,
                             &config,
-                            &mut next_deadline,
+                            &mut fixed_rate_clock,
                             current_interval,
                         );
-                        sleep.set(inner.runtime.sleep(current_interval));
+                        sleep_deadline = deadline_after(&inner, current_interval);
+                        sleep.set(arm_sleep(&inner, &config, sleep_deadline).await);
                     }
                 }
             }
-        }
+        };
 
-        let max_attempts = config
-            .retry_policy
-            .map_or(1, |policy| policy.max_retries() + 1);
-        let mut callback_succeeded = false;
+        *inner
+            .lock_profiled(&inner.next_fire, LockSite::NextFire)
+            .await = None;
+        let catching_up = catch_up_ticks > 0;
+        catch_up_ticks = catch_up_ticks.saturating_sub(1);
 
-        for attempt in 0..max_attempts {
-            let callback_result = match config.callback_timeout {
-                Some(timeout) => match time::timeout(timeout, callback.execute()).await {
-                    Ok(result) => result,
-                    Err(_) => Err(crate::errors::TimerError::callback_timed_out(timeout)),
-                },
-                None => callback.execute().await,
-            };
+        let enabled = config
+            .enabled_flag
+            .as_ref()
+            .is_none_or(|flag| flag.load(std::sync::atomic::Ordering::Relaxed));
+        let in_window = enabled
+            && config.active_window.is_none_or(|window| {
+                window_contains(
+                    window,
+                    TimeOfDay::from_system_time(inner.runtime.system_now()),
+                )
+            });
 
-            match callback_result {
-                Ok(()) => {
-                    success_count += 1;
-                    callback_succeeded = true;
-                    break;
-                }
-                Err(err) => {
-                    #[cfg(feature = "logging")]
-                    error!("Callback execution error: {}", err);
-                    failure_count += 1;
-                    last_error = Some(err.clone());
+        let is_leader = in_window
+            && config
+                .leadership
+                .as_ref()
+                .is_none_or(|gate| gate.is_leader());
 
-                    if attempt + 1 < max_attempts {
-                        if let Some(backoff) = retry_backoff_delay(config.retry_policy, attempt + 1)
-                        {
-                            if !backoff.is_zero() {
-                                inner.runtime.sleep(backoff).await;
-                            }
-                        }
-                    }
+        let mut tick_statistics = TimerStatistics::default();
+        if is_leader {
+            tick_count += 1;
+            let fired_at = inner.runtime.now();
+            let full_statistics = config.stats_level == StatsLevel::Full;
+            if let Some(previous) = last_fired_at.replace(fired_at).filter(|_| full_statistics) {
+                tick_statistics
+                    .inter_arrival_times
+                    .push(fired_at.saturating_duration_since(previous));
+            }
+            if !triggered && full_statistics {
+                let drift = fired_at.saturating_duration_since(sleep_deadline);
+                inner
+                    .lock_profiled(&inner.drift_histogram, LockSite::DriftHistogram)
+                    .await
+                    .record(drift);
+                if config.cadence == RecurringCadence::FixedRate && drift > config.tolerance {
+                    tick_statistics.late_executions = 1;
                 }
             }
-        }
-
-        if !callback_succeeded {
-            #[cfg(feature = "logging")]
-            if let Some(error) = &last_error {
-                error!("Callback execution exhausted retries: {}", error);
+            let turn = if triggered {
+                None
+            } else {
+                enter_dispatch(&inner, sleep_deadline).await
+            };
+            execute_tick(
+                &inner,
+                run_id,
+                started_at,
+                Tick {
+                    execution: tick_count,
+                    turn,
+                },
+                &config,
+                &callback,
+                &mut tick_statistics,
+            )
+            .await;
+            if config.count_errors_toward_limit || tick_statistics.successful_executions > 0 {
+                counted_ticks += 1;
             }
+        } else if in_window {
+            tick_statistics.follower_skips = 1;
+        } else {
+            tick_statistics.skipped_executions = 1;
         }
 
-        tick_count += 1;
-        next_sleep = next_sleep_duration(&inner, &config, &mut next_deadline, current_interval);
+        next_sleep = if catching_up {
+            sleep_after_catch_up(
+                &inner,
+                &config,
+                &fixed_rate_clock,
+                &union_clock,
+                current_interval,
+                catch_up_ticks,
+            )
+        } else {
+            next_sleep_duration(
+                &inner,
+                &config,
+                &mut fixed_rate_clock,
+                &mut union_clock,
+                current_interval,
+                &mut tick_statistics,
+            )
+        };
+        let statistics = merge_statistics(&inner, started_at, &tick_statistics).await;
+        let panicked = tick_statistics
+            .last_error
+            .as_ref()
+            .is_some_and(TimerError::is_callback_panicked);
 
-        let statistics = update_statistics(
-            &inner,
-            started_at,
-            tick_count,
-            success_count,
-            failure_count,
-            last_error.clone(),
-        )
-        .await;
+        if is_leader {
+            if let Some(error) = tick_statistics.last_error {
+                emit_event(
+                    &inner,
+                    TimerEvent::CallbackFailed {
+                        run_id,
+                        error,
+                        statistics: statistics.clone(),
+                    },
+                );
+            }
 
-        if let Some(error) = statistics.last_error.clone() {
             emit_event(
                 &inner,
-                TimerEvent::CallbackFailed {
+                TimerEvent::Tick {
                     run_id,
-                    error,
                     statistics: statistics.clone(),
                 },
             );
         }
 
-        emit_event(
+        let scheduled_at = deadline_after(&inner, next_sleep);
+        let interval_before_drain = current_interval;
+        let control = drain_post_tick_commands(
             &inner,
-            TimerEvent::Tick {
-                run_id,
-                statistics: statistics.clone(),
-            },
-        );
-
-        match drain_post_tick_commands(&inner, &mut rx, &mut current_interval, run_id).await {
-            RunControl::Continue => {}
+            &mut rx,
+            &mut current_interval,
+            &mut fire_now_pending,
+            run_id,
+        )
+        .await;
+        match control {
+            RunControl::Continue => {
+                // An adjustment requested during the tick replaces the sleep
+                // computed from the old interval, so it applies to the next tick.
+                if current_interval != interval_before_drain && catch_up_ticks == 0 {
+                    reset_recurring_deadline(
+                        &inner,
+                        &config,
+                        &mut fixed_rate_clock,
+                        current_interval,
+                    );
+                    next_sleep = apply_jitter(&inner, current_interval, config.jitter);
+                }
+            }
+            RunControl::Resumed => {
+                next_sleep = resume_schedule(
+                    &inner,
+                    &config,
+                    &mut fixed_rate_clock,
+                    &mut union_clock,
+                    current_interval,
+                    InterruptedSleep {
+                        deadline: scheduled_at,
+                        remaining: (current_interval == interval_before_drain)
+                            .then_some(next_sleep),
+                    },
+                    &mut catch_up_ticks,
+                );
+            }
             RunControl::Finish(reason) => {
-                let outcome = TimerOutcome {
-                    run_id,
-                    reason,
-                    statistics,
-                };
-                finish_run(&inner, outcome).await;
+                finish_run(
+                    &inner,
+                    TimerOutcome {
+                        run_id,
+                        reason,
+                        statistics,
+                    },
+                )
+                .await;
                 return;
             }
         }
 
-        if !config.recurring
+        // A `fire_now` requested while the callback ran executes before the
+        // run can complete, so the request is never silently dropped.
+        let statistics = if fire_now_pending {
+            if let RunControl::Finish(reason) = fire_pending(
+                &inner,
+                run_id,
+                started_at,
+                &mut tick_count,
+                &mut fire_now_pending,
+                &config,
+                &callback,
+            )
+            .await
+            {
+                finish_with(&inner, run_id, started_at, reason).await;
+                return;
+            }
+            merge_statistics(&inner, started_at, &TimerStatistics::default()).await
+        } else {
+            statistics
+        };
+
+        if panicked {
+            match panic_policy(&inner).await {
+                PanicPolicy::Ignore => {}
+                PanicPolicy::Stop => {
+                    finish_run(
+                        &inner,
+                        TimerOutcome {
+                            run_id,
+                            reason: TimerFinishReason::Stopped,
+                            statistics,
+                        },
+                    )
+                    .await;
+                    return;
+                }
+                // A one-shot would re-arm forever if its callback keeps
+                // panicking, so it completes as under `Ignore`.
+                PanicPolicy::Restart
+                    if !inner.recurring.load(std::sync::atomic::Ordering::SeqCst) => {}
+                PanicPolicy::Restart => {
+                    #[cfg(feature = "logging")]
+                    warn!("Restarting timer schedule after a callback panic");
+                    tick_count = 0;
+                    counted_ticks = 0;
+                    last_fired_at = None;
+                    catch_up_ticks = 0;
+                    start_deadline = None;
+                    union_clock = UnionClock::from_config(inner.runtime.now(), &config);
+                    next_sleep = if config.recurring {
+                        first_sleep_delay(&inner, &config, &mut union_clock)
+                    } else {
+                        config.interval
+                    };
+                    fixed_rate_clock = config.recurring.then(|| {
+                        FixedRateClock::new(deadline_after(&inner, next_sleep), current_interval)
+                    });
+                    continue;
+                }
+            }
+        }
+
+        // A promoted one-shot continues on its interval, not its deadline.
+        start_deadline = None;
+        if !inner.recurring.load(std::sync::atomic::Ordering::SeqCst)
             || config
                 .expiration_count
-                .is_some_and(|max_ticks| tick_count >= max_ticks)
+                .is_some_and(|max_ticks| counted_ticks >= max_ticks)
         {
-            let outcome = TimerOutcome {
-                run_id,
-                reason: TimerFinishReason::Completed,
-                statistics,
-            };
-            finish_run(&inner, outcome).await;
+            finish_run(
+                &inner,
+                TimerOutcome {
+                    run_id,
+                    reason: TimerFinishReason::Completed,
+                    statistics,
+                },
+            )
+            .await;
             return;
         }
     }
 }
 
+/// A scheduled execution and the dispatch turn held for its first attempt.
+struct Tick {
+    execution: usize,
+    turn: Option<DispatchTurn>,
+}
+
+async fn execute_tick<F>(
+    inner: &Arc<TimerInner>,
+    run_id: u64,
+    started_at: Instant,
+    tick: Tick,
+    config: &RunConfig,
+    callback: &F,
+    statistics: &mut TimerStatistics,
+) where
+    F: TimerCallback + 'static,
+{
+    let Tick {
+        execution,
+        mut turn,
+    } = tick;
+    let retry_policy = *inner.retry_policy.lock().await;
+    let max_attempts = retry_policy.map_or(1, |policy| policy.max_retries() + 1);
+    let mut callback_succeeded = false;
+    let tick_started_at = inner.runtime.now();
+    *inner
+        .lock_profiled(&inner.last_execution, LockSite::LastExecution)
+        .await = Some(tick_started_at);
+    inner.fired.store(true, std::sync::atomic::Ordering::SeqCst);
+
+    for attempt in 0..max_attempts {
+        wait_for_rate_limit(inner).await;
+        let permit = acquire_concurrency_permit(inner).await;
+        let attempt_started_at = inner.runtime.now();
+        let mut hard_timed_out = false;
+        let elapsed = active_elapsed(inner, started_at);
+        let context = callback_context(inner, run_id, execution, elapsed).await;
+        let execution_future = catch_unwind(callback.execute_with_context(context));
+        let callback_result = match config.callback_timeout {
+            Some(timeout) => match time::timeout(timeout, execution_future).await {
+                Ok(result) => result,
+                Err(_) => {
+                    hard_timed_out = true;
+                    Ok(Err(TimerError::callback_timed_out(timeout)))
+                }
+            },
+            None => execution_future.await,
+        };
+        // Release the slot and the dispatch turn before any retry backoff so
+        // waiting never blocks other timers.
+        drop(permit);
+        drop(turn.take());
+        let mut panicked = false;
+        let callback_result = callback_result.unwrap_or_else(|payload| {
+            panicked = true;
+            Err(TimerError::callback_panicked(panic_message(
+                payload.as_ref(),
+            )))
+        });
+
+        if hard_timed_out {
+            statistics.hard_timeouts += 1;
+        } else if let Some(soft_timeout) = config.soft_timeout {
+            let attempt_duration = inner
+                .runtime
+                .now()
+                .saturating_duration_since(attempt_started_at);
+            if attempt_duration > soft_timeout {
+                statistics.soft_timeouts += 1;
+                #[cfg(feature = "logging")]
+                warn!(
+                    "Callback execution took {:?}, exceeding the soft timeout of {:?}",
+                    attempt_duration, soft_timeout
+                );
+            }
+        }
+
+        match callback_result {
+            Ok(()) => {
+                statistics.successful_executions += 1;
+                callback_succeeded = true;
+                break;
+            }
+            Err(err) => {
+                #[cfg(feature = "logging")]
+                error!("Callback execution error: {}", err);
+                let error_handler = inner.error_handler.lock().await.clone();
+                if let Some(error_handler) = error_handler {
+                    error_handler(&err);
+                }
+                statistics.failed_executions += 1;
+                statistics.last_error = Some(err);
+
+                if panicked {
+                    break;
+                }
+
+                if attempt + 1 < max_attempts {
+                    statistics.retries += 1;
+                    if let Some(backoff) = retry_backoff_delay(retry_policy, attempt + 1) {
+                        if !backoff.is_zero() {
+                            inner.runtime.sleep(backoff).await;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if !callback_succeeded {
+        #[cfg(feature = "logging")]
+        if let Some(error) = &statistics.last_error {
+            error!("Callback execution exhausted retries: {}", error);
+        }
+    }
+
+    let duration = inner
+        .runtime
+        .now()
+        .saturating_duration_since(tick_started_at);
+    statistics.record_execution_duration(duration);
+    statistics.execution_count += 1;
+    if config.stats_level != StatsLevel::Full {
+        return;
+    }
+
+    *inner
+        .lock_profiled(&inner.last_result, LockSite::LastResult)
+        .await = Some(match &statistics.last_error {
+        Some(error) if !callback_succeeded => Err(error.clone()),
+        _ => Ok(()),
+    });
+
+    if config.history_capacity > 0 {
+        record_execution(
+            inner,
+            config.history_capacity,
+            ExecutionRecord {
+                run_id,
+                execution,
+                started_at: tick_started_at,
+                duration,
+                succeeded: callback_succeeded,
+            },
+        )
+        .await;
+    }
+}
+
+/// Polls `future`, converting a panic into an error carrying its payload.
+async fn catch_unwind<Fut>(future: Fut) -> Result<Fut::Output, Box<dyn Any + Send>>
+where
+    Fut: Future,
+{
+    let mut future = std::pin::pin!(future);
+    std::future::poll_fn(|cx| {
+        match std::panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    })
+    .await
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "callback panicked with a non-string payload".to_owned()
+    }
+}
+
+async fn panic_policy(inner: &Arc<TimerInner>) -> PanicPolicy {
+    let registry = inner.registry.lock().await.as_ref().and_then(Weak::upgrade);
+    match registry {
+        Some(registry) => *registry.panic_policy.read().await,
+        None => PanicPolicy::default(),
+    }
+}
+
+async fn callback_context(
+    inner: &Arc<TimerInner>,
+    run_id: u64,
+    execution: usize,
+    elapsed: Duration,
+) -> TimerContext {
+    let registry = inner
+        .registry
+        .lock()
+        .await
+        .as_ref()
+        .and_then(Weak::upgrade)
+        .map(TimerRegistry::from_inner);
+
+    TimerContext {
+        run_id,
+        execution,
+        elapsed,
+        label: inner.metadata.lock().await.label.clone(),
+        timer: Timer {
+            inner: Arc::clone(inner),
+        },
+        registry,
+        cancellation: inner.run_token.lock().await.clone(),
+    }
+}
+
+/// Describes the finished run to an `on_complete` or `on_stop` hook.
+async fn hook_context(inner: &Arc<TimerInner>, outcome: &TimerOutcome) -> TimerContext {
+    callback_context(
+        inner,
+        outcome.run_id,
+        outcome.statistics.execution_count,
+        outcome.statistics.elapsed_time,
+    )
+    .await
+}
+
+/// Waits until the registry lets this timer's tick due at `deadline` run.
+async fn enter_dispatch(inner: &Arc<TimerInner>, deadline: Instant) -> Option<DispatchTurn> {
+    let registry = inner
+        .registry
+        .lock()
+        .await
+        .as_ref()
+        .and_then(Weak::upgrade)?;
+    registry
+        .enter_dispatch(
+            &inner.runtime,
+            deadline,
+            inner.priority.load(Ordering::SeqCst),
+            inner.registry_id.load(Ordering::SeqCst),
+        )
+        .await
+}
+
+async fn acquire_concurrency_permit(inner: &Arc<TimerInner>) -> Option<OwnedSemaphorePermit> {
+    let semaphore = inner
+        .registry
+        .lock()
+        .await
+        .as_ref()
+        .and_then(Weak::upgrade)
+        .and_then(|registry| registry.concurrency.clone())?;
+    semaphore.acquire_owned().await.ok()
+}
+
+async fn wait_for_rate_limit(inner: &Arc<TimerInner>) {
+    let Some(registry) = inner.registry.lock().await.as_ref().and_then(Weak::upgrade) else {
+        return;
+    };
+    let delay = registry.reserve_execution(inner.runtime.now()).await;
+    drop(registry);
+    if !delay.is_zero() {
+        inner.runtime.sleep(delay).await;
+    }
+}
+
+async fn record_execution(inner: &Arc<TimerInner>, capacity: usize, record: ExecutionRecord) {
+    let mut history = inner.lock_profiled(&inner.history, LockSite::History).await;
+    if history.len() >= capacity {
+        history.pop_front();
+    }
+    history.push_back(record);
+}
+
+/// Returns the deadline `delay` from now, capped so huge delays cannot overflow.
+pub(super) fn deadline_after(inner: &Arc<TimerInner>, delay: Duration) -> Instant {
+    inner.runtime.now() + delay.min(FAR_FUTURE)
+}
+
 fn next_sleep_duration(
     inner: &Arc<TimerInner>,
     config: &RunConfig,
-    next_deadline: &mut Option<Instant>,
+    fixed_rate_clock: &mut Option<FixedRateClock>,
+    union_clock: &mut Option<UnionClock>,
     current_interval: Duration,
+    tick_statistics: &mut TimerStatistics,
 ) -> Duration {
+    if let Some(cron) = &config.cron {
+        return cron_delay(inner, cron, CRON_CLOCK_SKEW);
+    }
+    if let Some(clock) = union_clock {
+        let base = clock
+            .advance()
+            .saturating_duration_since(inner.runtime.now());
+        return apply_jitter(inner, base, config.jitter);
+    }
+
     let base = match config.cadence {
         RecurringCadence::FixedDelay => current_interval,
         RecurringCadence::FixedRate => {
             let now = inner.runtime.now();
-            let deadline = next_deadline.get_or_insert(now + current_interval);
-            *deadline += current_interval;
+            let clock =
+                fixed_rate_clock.get_or_insert_with(|| FixedRateClock::new(now, current_interval));
+            let mut deadline = clock.advance();
+            let overdue = deadline
+                .checked_add(config.tolerance)
+                .is_some_and(|latest| latest < now);
+            if overdue
+                && inner
+                    .skip_overlaps
+                    .load(std::sync::atomic::Ordering::SeqCst)
+            {
+                let overdue_from = clock.ticks;
+                clock.skip_until(now);
+                tick_statistics.missed_executions +=
+                    usize::try_from(clock.ticks - overdue_from).unwrap_or(usize::MAX);
+                deadline = clock.deadline();
+            }
             deadline.saturating_duration_since(now)
         }
     };
@@ -318,35 +812,300 @@ fn next_sleep_duration(
     apply_jitter(inner, base, config.jitter)
 }
 
+/// Publishes `deadline` as the next fire time and returns the sleep until it.
+async fn arm_sleep(inner: &Arc<TimerInner>, config: &RunConfig, deadline: Instant) -> SleepFuture {
+    *inner
+        .lock_profiled(&inner.next_fire, LockSite::NextFire)
+        .await = Some(deadline);
+    gate_on_event_room(inner, config, inner.runtime.sleep_until(deadline))
+}
+
+fn gate_on_event_room(
+    inner: &Arc<TimerInner>,
+    config: &RunConfig,
+    sleep: SleepFuture,
+) -> SleepFuture {
+    if !config.pause_on_full_events {
+        return sleep;
+    }
+
+    let inner = Arc::clone(inner);
+    Box::pin(async move {
+        sleep.await;
+        loop {
+            // Register before checking so a drain between the check and the
+            // wait is not missed.
+            let drained = inner.events_drained.notified();
+            tokio::pin!(drained);
+            drained.as_mut().enable();
+            if !inner
+                .events_enabled
+                .load(std::sync::atomic::Ordering::Relaxed)
+                || inner.event_tx.len() + EVENTS_PER_TICK.min(inner.event_capacity)
+                    <= inner.event_capacity
+            {
+                break;
+            }
+            drained.await;
+        }
+    })
+}
+
 fn reset_recurring_deadline(
     inner: &Arc<TimerInner>,
     config: &RunConfig,
-    next_deadline: &mut Option<Instant>,
+    fixed_rate_clock: &mut Option<FixedRateClock>,
     current_interval: Duration,
 ) {
     if config.recurring && config.cadence == RecurringCadence::FixedRate {
-        *next_deadline = Some(inner.runtime.now() + current_interval);
+        let mut clock = FixedRateClock::new(inner.runtime.now(), current_interval);
+        clock.advance();
+        *fixed_rate_clock = Some(clock);
+    }
+}
+
+/// Fixed-rate deadlines derived from an origin and an integer tick index.
+///
+/// Each deadline is computed as `origin + ticks * interval` rather than by
+/// repeatedly adding the interval, so no error compounds across ticks.
+struct FixedRateClock {
+    origin: Instant,
+    interval: Duration,
+    ticks: u64,
+}
+
+impl FixedRateClock {
+    fn new(origin: Instant, interval: Duration) -> Self {
+        Self {
+            origin,
+            interval,
+            ticks: 0,
+        }
+    }
+
+    fn advance(&mut self) -> Instant {
+        self.ticks += 1;
+        self.deadline()
+    }
+
+    /// Skips ticks so the next deadline is not before `now`.
+    fn skip_until(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.origin).as_nanos();
+        let interval = self.interval.as_nanos().max(1);
+        let due = u64::try_from(elapsed.div_ceil(interval)).unwrap_or(u64::MAX);
+        self.ticks = self.ticks.max(due);
+    }
+
+    fn deadline(&self) -> Instant {
+        let nanos = self
+            .interval
+            .as_nanos()
+            .saturating_mul(u128::from(self.ticks));
+        let offset = Duration::new(
+            u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX),
+            (nanos % 1_000_000_000) as u32,
+        );
+        self.origin
+            .checked_add(offset)
+            .unwrap_or_else(|| self.origin + FAR_FUTURE)
     }
 }
 
+/// Deadlines for the union of several recurring schedules.
+///
+/// Each schedule keeps its own [`FixedRateClock`] anchored at its first fire;
+/// the union fires at the earliest pending deadline and advances every clock
+/// due within [`UNION_COALESCE_WINDOW`] of it, so coinciding fires run once.
+struct UnionClock {
+    clocks: Vec<FixedRateClock>,
+}
+
+impl UnionClock {
+    fn from_config(started_at: Instant, config: &RunConfig) -> Option<Self> {
+        if config.union_schedules.is_empty() {
+            return None;
+        }
+
+        let clocks = config
+            .union_schedules
+            .iter()
+            .map(|schedule| {
+                let first = schedule.initial_delay().unwrap_or(schedule.interval());
+                FixedRateClock::new(started_at + first.min(FAR_FUTURE), schedule.interval())
+            })
+            .collect();
+        Some(Self { clocks })
+    }
+
+    fn peek(&self) -> Instant {
+        self.clocks
+            .iter()
+            .map(FixedRateClock::deadline)
+            .min()
+            .expect("union clocks are never empty")
+    }
+
+    fn advance(&mut self) -> Instant {
+        let next = self.peek();
+        let window_end = next + UNION_COALESCE_WINDOW;
+        for clock in &mut self.clocks {
+            while clock.deadline() <= window_end {
+                clock.advance();
+            }
+        }
+        next
+    }
+
+    fn skip_until(&mut self, now: Instant) {
+        for clock in &mut self.clocks {
+            clock.skip_until(now);
+        }
+    }
+}
+
+/// The sleep a pause interrupted.
+struct InterruptedSleep {
+    /// When the sleep would have ended.
+    deadline: Instant,
+    /// Time left in the sleep when the run paused, or `None` when the
+    /// interval changed during the pause.
+    remaining: Option<Duration>,
+}
+
+/// Reschedules a recurring run after a pause and queues any catch-up executions.
+///
+/// Without catch-up, the run only waits out the part of the interrupted
+/// sleep that was left when it paused. Returns the delay before the next
+/// execution.
+fn resume_schedule(
+    inner: &Arc<TimerInner>,
+    config: &RunConfig,
+    fixed_rate_clock: &mut Option<FixedRateClock>,
+    union_clock: &mut Option<UnionClock>,
+    current_interval: Duration,
+    interrupted: InterruptedSleep,
+    catch_up_ticks: &mut usize,
+) -> Duration {
+    if let Some(cron) = &config.cron {
+        *catch_up_ticks = 0;
+        return cron_delay(inner, cron, Duration::ZERO);
+    }
+
+    let now = inner.runtime.now();
+    reset_recurring_deadline(inner, config, fixed_rate_clock, current_interval);
+    let missed = missed_intervals(interrupted.deadline, now, current_interval);
+    *catch_up_ticks = config.resume_catch_up.catch_up_ticks(missed);
+    if let Some(clock) = union_clock.as_mut() {
+        clock.skip_until(now);
+    }
+
+    if *catch_up_ticks > 0 {
+        return Duration::ZERO;
+    }
+    if let Some(clock) = union_clock {
+        return clock.advance().saturating_duration_since(now);
+    }
+
+    let delay = interrupted.remaining.unwrap_or(current_interval);
+    if fixed_rate_clock.is_some() {
+        *fixed_rate_clock = Some(FixedRateClock::new(now + delay, current_interval));
+    }
+    delay
+}
+
+fn sleep_after_catch_up(
+    inner: &Arc<TimerInner>,
+    config: &RunConfig,
+    fixed_rate_clock: &Option<FixedRateClock>,
+    union_clock: &Option<UnionClock>,
+    current_interval: Duration,
+    remaining_catch_up_ticks: usize,
+) -> Duration {
+    if remaining_catch_up_ticks > 0 {
+        return Duration::ZERO;
+    }
+    if let Some(clock) = union_clock {
+        return clock.peek().saturating_duration_since(inner.runtime.now());
+    }
+
+    match (config.cadence, fixed_rate_clock) {
+        (RecurringCadence::FixedRate, Some(clock)) => clock
+            .deadline()
+            .saturating_duration_since(inner.runtime.now()),
+        _ => current_interval,
+    }
+}
+
+/// Time until the cron expression next matches after the wall clock plus `skew`.
+fn cron_delay(inner: &Arc<TimerInner>, cron: &CronSchedule, skew: Duration) -> Duration {
+    let now = inner.runtime.system_now();
+    cron.next_after(now + skew)
+        .and_then(|next| next.duration_since(now).ok())
+        .unwrap_or(FAR_FUTURE)
+}
+
+fn missed_intervals(deadline: Instant, now: Instant, interval: Duration) -> usize {
+    if now < deadline {
+        return 0;
+    }
+    if interval.is_zero() {
+        return 1;
+    }
+
+    let extra = now.duration_since(deadline).as_nanos() / interval.as_nanos();
+    usize::try_from(extra)
+        .unwrap_or(usize::MAX)
+        .saturating_add(1)
+}
+
 enum RunControl {
     Continue,
+    Resumed,
     Finish(TimerFinishReason),
 }
 
+/// Waits until a paused run resumes or finishes, recording the time spent paused.
 async fn wait_while_paused(
     inner: &Arc<TimerInner>,
     rx: &mut mpsc::UnboundedReceiver<TimerCommand>,
     current_interval: &mut Duration,
+    fire_now_pending: &mut bool,
+) -> RunControl {
+    if *inner.lock_profiled(&inner.state, LockSite::State).await != TimerState::Paused {
+        return RunControl::Continue;
+    }
+
+    let paused_at = inner.runtime.now();
+    let control = wait_for_resume(inner, rx, current_interval, fire_now_pending).await;
+    let paused = inner.runtime.now().saturating_duration_since(paused_at);
+    let paused_nanos = u64::try_from(paused.as_nanos()).unwrap_or(u64::MAX);
+    inner
+        .paused_nanos
+        .fetch_add(paused_nanos, std::sync::atomic::Ordering::SeqCst);
+    control
+}
+
+async fn wait_for_resume(
+    inner: &Arc<TimerInner>,
+    rx: &mut mpsc::UnboundedReceiver<TimerCommand>,
+    current_interval: &mut Duration,
+    fire_now_pending: &mut bool,
 ) -> RunControl {
     loop {
-        if *inner.state.lock().await != TimerState::Paused {
+        if *inner.lock_profiled(&inner.state, LockSite::State).await != TimerState::Paused {
             return RunControl::Continue;
         }
 
-        match rx.recv().await {
+        inner
+            .parked_while_paused
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        let command = rx.recv().await;
+        inner
+            .parked_while_paused
+            .store(false, std::sync::atomic::Ordering::SeqCst);
+        match command {
             Some(TimerCommand::Resume) => {
-                *inner.state.lock().await = TimerState::Running;
+                inner.set_state(TimerState::Running).await;
                 emit_event(
                     inner,
                     TimerEvent::Resumed {
@@ -355,14 +1114,15 @@ async fn wait_while_paused(
                             .load(std::sync::atomic::Ordering::SeqCst),
                     },
                 );
-                return RunControl::Continue;
+                return RunControl::Resumed;
             }
             Some(TimerCommand::Cancel) => return RunControl::Finish(TimerFinishReason::Cancelled),
             Some(TimerCommand::Stop) => return RunControl::Finish(TimerFinishReason::Stopped),
             Some(TimerCommand::SetInterval(new_interval)) => {
                 *current_interval = new_interval;
             }
-            Some(TimerCommand::Pause) => {}
+            Some(TimerCommand::FireNow) => *fire_now_pending = true,
+            Some(TimerCommand::Pause) | Some(TimerCommand::TriggerNow) => {}
             None => return RunControl::Finish(TimerFinishReason::Cancelled),
         }
     }
@@ -372,16 +1132,18 @@ async fn drain_post_tick_commands(
     inner: &Arc<TimerInner>,
     rx: &mut mpsc::UnboundedReceiver<TimerCommand>,
     current_interval: &mut Duration,
+    fire_now_pending: &mut bool,
     run_id: u64,
 ) -> RunControl {
     loop {
         match rx.try_recv() {
             Ok(TimerCommand::Pause) => {
-                *inner.state.lock().await = TimerState::Paused;
+                inner.set_state(TimerState::Paused).await;
                 emit_event(inner, TimerEvent::Paused { run_id });
-                return wait_while_paused(inner, rx, current_interval).await;
+                return wait_while_paused(inner, rx, current_interval, fire_now_pending).await;
             }
-            Ok(TimerCommand::Resume) => {}
+            Ok(TimerCommand::FireNow) => *fire_now_pending = true,
+            Ok(TimerCommand::Resume) | Ok(TimerCommand::TriggerNow) => {}
             Ok(TimerCommand::Cancel) => return RunControl::Finish(TimerFinishReason::Cancelled),
             Ok(TimerCommand::Stop) => return RunControl::Finish(TimerFinishReason::Stopped),
             Ok(TimerCommand::SetInterval(new_interval)) => {
@@ -395,30 +1157,131 @@ async fn drain_post_tick_commands(
     }
 }
 
-async fn update_statistics(
+/// Time since the run started, excluding the time it spent paused.
+fn active_elapsed(inner: &Arc<TimerInner>, started_at: Instant) -> Duration {
+    let paused = Duration::from_nanos(inner.paused_nanos.load(std::sync::atomic::Ordering::SeqCst));
+    inner
+        .runtime
+        .now()
+        .saturating_duration_since(started_at)
+        .saturating_sub(paused)
+}
+
+/// Runs a queued `fire_now` request, if any.
+async fn fire_pending<F>(
     inner: &Arc<TimerInner>,
+    run_id: u64,
     started_at: Instant,
-    tick_count: usize,
-    success_count: usize,
-    failure_count: usize,
-    last_error: Option<crate::errors::TimerError>,
-) -> super::TimerStatistics {
-    let statistics = super::TimerStatistics {
-        execution_count: tick_count,
-        successful_executions: success_count,
-        failed_executions: failure_count,
-        elapsed_time: started_at.elapsed(),
-        last_error,
-    };
+    tick_count: &mut usize,
+    fire_now_pending: &mut bool,
+    config: &RunConfig,
+    callback: &F,
+) -> RunControl
+where
+    F: TimerCallback + 'static,
+{
+    if !std::mem::take(fire_now_pending) {
+        return RunControl::Continue;
+    }
+    *tick_count += 1;
+    fire_out_of_cycle(inner, run_id, started_at, *tick_count, config, callback).await
+}
 
-    *inner.statistics.lock().await = statistics.clone();
-    statistics
+/// Executes a `fire_now` request without touching the pending deadline.
+///
+/// A panic only ends the run under [`PanicPolicy::Stop`]; the schedule is
+/// never restarted for an out-of-cycle execution.
+async fn fire_out_of_cycle<F>(
+    inner: &Arc<TimerInner>,
+    run_id: u64,
+    started_at: Instant,
+    execution: usize,
+    config: &RunConfig,
+    callback: &F,
+) -> RunControl
+where
+    F: TimerCallback + 'static,
+{
+    let mut tick_statistics = TimerStatistics::default();
+    execute_tick(
+        inner,
+        run_id,
+        started_at,
+        Tick {
+            execution,
+            turn: None,
+        },
+        config,
+        callback,
+        &mut tick_statistics,
+    )
+    .await;
+    let statistics = merge_statistics(inner, started_at, &tick_statistics).await;
+    let panicked = tick_statistics
+        .last_error
+        .as_ref()
+        .is_some_and(TimerError::is_callback_panicked);
+    if let Some(error) = tick_statistics.last_error {
+        emit_event(
+            inner,
+            TimerEvent::CallbackFailed {
+                run_id,
+                error,
+                statistics: statistics.clone(),
+            },
+        );
+    }
+    emit_event(inner, TimerEvent::Tick { run_id, statistics });
+
+    if panicked && panic_policy(inner).await == PanicPolicy::Stop {
+        RunControl::Finish(TimerFinishReason::Stopped)
+    } else {
+        RunControl::Continue
+    }
+}
+
+async fn merge_statistics(
+    inner: &Arc<TimerInner>,
+    started_at: Instant,
+    tick_statistics: &TimerStatistics,
+) -> TimerStatistics {
+    match inner.stats_level() {
+        StatsLevel::Off => TimerStatistics::default(),
+        StatsLevel::Counters => {
+            inner.counters.record(tick_statistics);
+            TimerStatistics {
+                elapsed_time: active_elapsed(inner, started_at),
+                ..inner.counters.snapshot()
+            }
+        }
+        StatsLevel::Full => {
+            let mut statistics = inner.lock_statistics().await;
+            statistics.merge(tick_statistics);
+            statistics.elapsed_time = active_elapsed(inner, started_at);
+            statistics.clone()
+        }
+    }
 }
 
-fn first_sleep_delay(inner: &Arc<TimerInner>, config: &RunConfig) -> Duration {
-    let base = match config.start_deadline {
-        Some(deadline) => deadline.saturating_duration_since(inner.runtime.now()),
-        None => config.initial_delay.unwrap_or(config.interval),
+fn first_sleep_delay(
+    inner: &Arc<TimerInner>,
+    config: &RunConfig,
+    union_clock: &mut Option<UnionClock>,
+) -> Duration {
+    if config.immediate_first_tick {
+        return Duration::ZERO;
+    }
+
+    if let Some(cron) = &config.cron {
+        return cron_delay(inner, cron, Duration::ZERO);
+    }
+
+    let base = match (config.start_deadline, union_clock) {
+        (Some(deadline), _) => deadline.saturating_duration_since(inner.runtime.now()),
+        (None, Some(clock)) => clock
+            .advance()
+            .saturating_duration_since(inner.runtime.now()),
+        (None, None) => config.initial_delay.unwrap_or(config.interval),
     };
 
     if config.recurring {
@@ -434,31 +1297,44 @@ fn retry_backoff_delay(retry_policy: Option<RetryPolicy>, retry_number: usize) -
 
 fn apply_jitter(inner: &Arc<TimerInner>, base: Duration, jitter: Option<Duration>) -> Duration {
     match jitter {
-        Some(max_jitter) => base.saturating_add(inner.runtime.sample_jitter(max_jitter)),
+        Some(max_jitter) => base.saturating_add(sample_jitter(inner, max_jitter)),
         None => base,
     }
 }
 
-async fn snapshot_outcome(
+/// Draws jitter from the registry's seeded RNG when there is one.
+fn sample_jitter(inner: &Arc<TimerInner>, max_jitter: Duration) -> Duration {
+    let seeded = inner
+        .jitter_rng
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    match seeded {
+        Some(rng) if !max_jitter.is_zero() => {
+            let jitter_nanos = max_jitter.as_nanos().min(u64::MAX as u128) as u64;
+            let mut rng = rng.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            Duration::from_nanos(rng.u64(0..=jitter_nanos))
+        }
+        _ => inner.runtime.sample_jitter(max_jitter),
+    }
+}
+
+async fn finish_with(
     inner: &Arc<TimerInner>,
+    run_id: u64,
+    started_at: Instant,
     reason: TimerFinishReason,
-    snapshot: RunSnapshot,
-) -> TimerOutcome {
-    let statistics = update_statistics(
+) {
+    let statistics = merge_statistics(inner, started_at, &TimerStatistics::default()).await;
+    finish_run(
         inner,
-        snapshot.started_at,
-        snapshot.tick_count,
-        snapshot.success_count,
-        snapshot.failure_count,
-        snapshot.last_error,
+        TimerOutcome {
+            run_id,
+            reason,
+            statistics,
+        },
     )
     .await;
-
-    TimerOutcome {
-        run_id: snapshot.run_id,
-        reason,
-        statistics,
-    }
 }
 
 pub(super) fn emit_event(inner: &Arc<TimerInner>, event: TimerEvent) {
@@ -473,13 +1349,44 @@ pub(super) fn emit_event(inner: &Arc<TimerInner>, event: TimerEvent) {
 }
 
 pub(super) async fn finish_run(inner: &Arc<TimerInner>, outcome: TimerOutcome) {
+    // Publish the outcome before clearing the run id so a concurrent `join`
+    // never sees neither an active run nor a finished one.
+    *inner.last_outcome.lock().await = Some(outcome.clone());
     inner
         .active_run_id
         .store(0, std::sync::atomic::Ordering::SeqCst);
-    *inner.state.lock().await = TimerState::Stopped;
+    inner
+        .set_state(if outcome.reason == TimerFinishReason::Completed {
+            TimerState::Completed
+        } else {
+            TimerState::Stopped
+        })
+        .await;
     *inner.command_tx.lock().await = None;
     *inner.handle.lock().await = None;
-    *inner.last_outcome.lock().await = Some(outcome.clone());
+
+    if outcome.reason == TimerFinishReason::Completed {
+        let complete_hook = inner.complete_hook.lock().await.clone();
+        if let Some(complete_hook) = complete_hook {
+            let context = hook_context(inner, &outcome).await;
+            if let Err(_err) = complete_hook.execute_with_context(context).await {
+                #[cfg(feature = "logging")]
+                error!("Completion hook execution error: {}", _err);
+            }
+        }
+    }
+
+    let stop_hook = inner.stop_hook.lock().await.clone();
+    if let Some(stop_hook) = stop_hook {
+        let context = hook_context(inner, &outcome).await;
+        if let Err(_err) = stop_hook.execute_with_context(context).await {
+            #[cfg(feature = "logging")]
+            error!("Stop hook execution error: {}", _err);
+        }
+    }
+
+    // Release tasks scoped to the run, such as pending `pause_for` resumes.
+    inner.run_token.lock().await.cancel();
     inner.completion_tx.send_replace(Some(outcome.clone()));
 
     emit_event(inner, TimerEvent::Finished(outcome));
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use super::driver::MockRuntime;
+use super::Timer;
+use crate::registry::TimerRegistry;
+
+/// Deterministic scheduler that drives mocked timers without real sleeping.
+///
+/// Timers and registries created through the scheduler share its mocked
+/// clock. Advancing the scheduler stops at every pending deadline in order,
+/// so a recurring timer fires once per elapsed interval even when the
+/// scheduler jumps across several intervals at once.
+#[derive(Clone)]
+pub struct TestScheduler {
+    runtime: MockRuntime,
+    tick: Duration,
+}
+
+impl TestScheduler {
+    /// Creates a scheduler whose [`TestScheduler::advance`] steps by `tick`.
+    pub fn new(tick: Duration) -> Self {
+        Self {
+            runtime: MockRuntime::new(),
+            tick,
+        }
+    }
+
+    /// Returns the duration of a single scheduler tick.
+    pub fn tick(&self) -> Duration {
+        self.tick
+    }
+
+    /// Returns the mocked runtime backing the scheduler.
+    pub fn runtime(&self) -> &MockRuntime {
+        &self.runtime
+    }
+
+    /// Creates a timer driven by the scheduler.
+    pub fn timer(&self) -> Timer {
+        Timer::new_with_runtime(self.runtime.handle(), true)
+    }
+
+    /// Creates a registry whose timers are driven by the scheduler.
+    pub fn registry(&self) -> TimerRegistry {
+        TimerRegistry::with_runtime(self.runtime.handle())
+    }
+
+    /// Advances by `ticks` scheduler ticks, running every due callback in order.
+    pub async fn advance(&self, ticks: u32) {
+        self.advance_time(self.tick.saturating_mul(ticks)).await;
+    }
+
+    /// Advances by `duration`, running every due callback in order.
+    pub async fn advance_time(&self, duration: Duration) {
+        self.runtime.advance_through(duration).await;
+    }
+}
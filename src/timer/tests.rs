@@ -149,6 +149,367 @@ async fn interval_adjustments_apply_to_future_ticks() {
     assert_eq!(executions.load(Ordering::SeqCst), 2);
 }
 
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn interval_adjustments_from_the_callback_apply_to_the_next_tick() {
+    for cadence in [RecurringCadence::FixedDelay, RecurringCadence::FixedRate] {
+        let started_at = Instant::now();
+        let fires = Arc::new(StdMutex::new(Vec::new()));
+        let recorded = Arc::clone(&fires);
+        let timer = Timer::new();
+        timer
+            .start_recurring(
+                RecurringSchedule::new(Duration::from_secs(10)).with_cadence(cadence),
+                ContextCallback::new(move |context: TimerContext| {
+                    let recorded = Arc::clone(&recorded);
+                    async move {
+                        recorded
+                            .lock()
+                            .unwrap()
+                            .push(started_at.elapsed().as_secs());
+                        if context.execution() == 1 {
+                            context
+                                .timer()
+                                .request_adjust_interval(Duration::from_secs(3))
+                                .await?;
+                        }
+                        Ok(())
+                    }
+                }),
+            )
+            .await
+            .unwrap();
+        settle().await;
+
+        for _ in 0..16 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+
+        assert_eq!(*fires.lock().unwrap(), vec![10, 13, 16], "{cadence:?}");
+        timer.stop().await.unwrap();
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn context_reports_execution_elapsed_time_and_label() {
+    let seen = Arc::new(StdMutex::new(Vec::new()));
+    let recorded = Arc::clone(&seen);
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(2)))
+        .label("heartbeat")
+        .start(ContextCallback::new(move |context: TimerContext| {
+            let recorded = Arc::clone(&recorded);
+            async move {
+                recorded.lock().unwrap().push((
+                    context.execution(),
+                    context.elapsed_time().as_secs(),
+                    context.label().map(str::to_string),
+                ));
+                Ok(())
+            }
+        }))
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..3 {
+        advance(Duration::from_secs(2)).await;
+        settle().await;
+    }
+    timer.pause().await.unwrap();
+    settle().await;
+    advance(Duration::from_secs(5)).await;
+    timer.resume().await.unwrap();
+    settle().await;
+    advance(Duration::from_secs(2)).await;
+    settle().await;
+
+    let label = Some("heartbeat".to_string());
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            (1, 2, label.clone()),
+            (2, 4, label.clone()),
+            (3, 6, label.clone()),
+            (4, 8, label),
+        ]
+    );
+    timer.stop().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn stop_graceful_waits_for_the_callback_until_the_timeout() {
+    for (timeout, expected_reason, expected_finished) in [
+        (Duration::from_secs(10), TimerFinishReason::Stopped, 1),
+        (Duration::MAX, TimerFinishReason::Stopped, 1),
+        (Duration::from_secs(1), TimerFinishReason::Cancelled, 0),
+    ] {
+        let finished = Arc::new(AtomicUsize::new(0));
+        let finished_for_callback = Arc::clone(&finished);
+        let timer = Timer::new();
+        timer
+            .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), move || {
+                let finished = Arc::clone(&finished_for_callback);
+                async move {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    finished.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            })
+            .await
+            .unwrap();
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+
+        let outcome = timer.stop_graceful(timeout).await.unwrap();
+        assert_eq!(outcome.reason, expected_reason, "{timeout:?}");
+        assert_eq!(finished.load(Ordering::SeqCst), expected_finished);
+        assert_eq!(timer.get_state().await, TimerState::Stopped);
+    }
+
+    assert!(Timer::new()
+        .stop_graceful(Duration::from_secs(1))
+        .await
+        .unwrap_err()
+        .is_not_running());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn fire_now_runs_out_of_cycle_without_shifting_the_schedule() {
+    let started_at = Instant::now();
+    let fires = Arc::new(StdMutex::new(Vec::new()));
+    let recorded = Arc::clone(&fires);
+    let timer = Timer::new();
+    assert!(timer.fire_now().await.unwrap_err().is_not_running());
+
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(10)).with_expiration_count(2),
+            move || {
+                let recorded = Arc::clone(&recorded);
+                async move {
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push(started_at.elapsed().as_secs());
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(3)).await;
+    timer.fire_now().await.unwrap();
+    settle().await;
+    for _ in 0..17 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(*fires.lock().unwrap(), vec![3, 10, 20]);
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert!(timer.fire_now().await.unwrap_err().is_not_running());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn fire_now_from_a_running_callback_runs_after_it_finishes() {
+    let started_at = Instant::now();
+    let fires = Arc::new(StdMutex::new(Vec::new()));
+    let recorded = Arc::clone(&fires);
+    let timer = Timer::new();
+    timer
+        .start_once(
+            Duration::from_secs(1),
+            ContextCallback::new(move |context: TimerContext| {
+                let recorded = Arc::clone(&recorded);
+                async move {
+                    let first = {
+                        let mut fires = recorded.lock().unwrap();
+                        fires.push(started_at.elapsed().as_millis());
+                        fires.len() == 1
+                    };
+                    if first {
+                        context.timer().fire_now().await?;
+                        tokio::time::sleep(Duration::from_millis(500)).await;
+                    }
+                    Ok(())
+                }
+            }),
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    advance(Duration::from_millis(500)).await;
+    settle().await;
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(*fires.lock().unwrap(), vec![1_000, 1_500]);
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 2);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn elapsed_time_excludes_paused_intervals() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(4)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(3)).await;
+    settle().await;
+    timer.pause().await.unwrap();
+    settle().await;
+    advance(Duration::from_secs(20)).await;
+    settle().await;
+    timer.resume().await.unwrap();
+    settle().await;
+
+    for _ in 0..7 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    let outcome = timer.stop().await.unwrap();
+
+    assert!(outcome.statistics.execution_count > 0);
+    assert_eq!(outcome.statistics.elapsed_time, Duration::from_secs(10));
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn elapsed_time_follows_the_mock_clock() {
+    let (timer, runtime) = Timer::new_mocked();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    runtime.settle().await;
+
+    for _ in 0..3 {
+        runtime.advance(Duration::from_secs(1)).await;
+    }
+    timer.pause().await.unwrap();
+    runtime.settle().await;
+    runtime.advance(Duration::from_secs(5)).await;
+    timer.resume().await.unwrap();
+    runtime.settle().await;
+    for _ in 0..2 {
+        runtime.advance(Duration::from_secs(1)).await;
+    }
+    let outcome = timer.stop().await.unwrap();
+
+    assert_eq!(outcome.statistics.execution_count, 5);
+    assert_eq!(outcome.statistics.elapsed_time, Duration::from_secs(5));
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn statistics_track_callback_failures_alongside_successes() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(5),
+            ContextCallback::new(|context: TimerContext| async move {
+                if context.execution() % 2 == 0 {
+                    Err(TimerError::callback_failed(format!(
+                        "tick {} failed",
+                        context.execution()
+                    )))
+                } else {
+                    Ok(())
+                }
+            }),
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..5 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let statistics = timer.join().await.unwrap().statistics;
+    assert_eq!(statistics.execution_count, 5);
+    assert_eq!(statistics.successful_executions, 3);
+    assert_eq!(statistics.failed_executions, 2);
+    assert_eq!(
+        statistics.last_error,
+        Some(TimerError::callback_failed("tick 4 failed"))
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn demoting_a_recurring_timer_stops_it_after_one_more_tick() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)),
+            CountingCallback {
+                executions: Arc::clone(&executions),
+                fail: false,
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    timer.set_recurring(false).await.unwrap();
+
+    for _ in 0..3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(executions.load(Ordering::SeqCst), 3);
+    assert!(timer.set_recurring(true).await.is_err());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn promoting_a_one_shot_keeps_it_firing_every_interval() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .start_at(
+            Instant::now() + Duration::from_secs(2),
+            CountingCallback {
+                executions: Arc::clone(&executions),
+                fail: false,
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    timer.set_recurring(true).await.unwrap();
+
+    for _ in 0..6 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    assert_eq!(executions.load(Ordering::SeqCst), 3);
+    assert_eq!(timer.get_state().await, TimerState::Running);
+    timer.stop().await.unwrap();
+}
+
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn events_are_emitted_for_key_lifecycle_changes() {
     let timer = Timer::new();
@@ -311,6 +672,125 @@ async fn paused_builder_start_waits_for_resume() {
     );
 }
 
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn union_timer_merges_schedules_and_collapses_coinciding_fires() {
+    let started_at = Instant::now();
+    let fires = Arc::new(StdMutex::new(Vec::new()));
+    let recorded = Arc::clone(&fires);
+    let timer = Timer::new();
+    timer
+        .start_union(
+            vec![
+                RecurringSchedule::new(Duration::from_secs(15)),
+                RecurringSchedule::new(Duration::from_secs(60))
+                    .with_initial_delay(Duration::from_millis(60_005)),
+            ],
+            move || {
+                let recorded = Arc::clone(&recorded);
+                async move {
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push(started_at.elapsed().as_secs());
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..125 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    assert_eq!(
+        *fires.lock().unwrap(),
+        vec![15, 30, 45, 60, 75, 90, 105, 120]
+    );
+    assert!(timer
+        .adjust_interval(Duration::from_secs(1))
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert!(timer
+        .request_adjust_interval(Duration::from_secs(1))
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    timer.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn union_timer_requires_valid_schedules() {
+    let timer = Timer::new();
+    assert!(timer
+        .start_union(Vec::new(), || async { Ok(()) })
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert!(timer
+        .start_union(
+            vec![
+                RecurringSchedule::new(Duration::from_secs(1)),
+                RecurringSchedule::new(Duration::ZERO),
+            ],
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn immediate_first_tick_counts_toward_the_expiration_count() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .start_recurring_immediate(
+            RecurringSchedule::new(Duration::from_secs(2)).with_expiration_count(3),
+            CountingCallback {
+                executions: Arc::clone(&executions),
+                fail: false,
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+
+    for expected in 2..=3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        assert_eq!(executions.load(Ordering::SeqCst), expected);
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+}
+
+#[tokio::test]
+async fn immediate_first_tick_rejects_an_initial_delay() {
+    let error = Timer::recurring(
+        RecurringSchedule::new(Duration::from_secs(1))
+            .with_initial_delay(Duration::from_secs(1))
+            .with_immediate_first_tick(),
+    )
+    .start(|| async { Ok(()) })
+    .await
+    .err()
+    .unwrap();
+    assert!(error.is_invalid_parameter());
+}
+
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn builder_initial_delay_controls_the_first_recurring_tick() {
     let executions = Arc::new(AtomicUsize::new(0));
@@ -373,6 +853,34 @@ async fn callback_timeout_counts_as_a_failed_execution() {
         .is_some_and(TimerError::is_callback_timed_out));
 }
 
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn callback_timeout_keeps_a_recurring_timer_ticking_after_a_hang() {
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(3))
+            .callback_timeout(Duration::from_secs(2))
+            .start(ContextCallback::new(|context: TimerContext| async move {
+                if context.execution() == 1 {
+                    std::future::pending::<()>().await;
+                }
+                Ok::<(), TimerError>(())
+            }))
+            .await
+            .unwrap();
+    settle().await;
+
+    for _ in 0..5 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert_eq!(outcome.statistics.hard_timeouts, 1);
+    assert_eq!(outcome.statistics.failed_executions, 1);
+    assert_eq!(outcome.statistics.successful_executions, 2);
+}
+
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn retry_policy_retries_failed_callbacks_before_succeeding() {
     let attempts = Arc::new(AtomicUsize::new(0));
@@ -401,11 +909,173 @@ async fn retry_policy_retries_failed_callbacks_before_succeeding() {
     assert_eq!(outcome.statistics.execution_count, 1);
     assert_eq!(outcome.statistics.failed_executions, 2);
     assert_eq!(outcome.statistics.successful_executions, 1);
+    assert_eq!(outcome.statistics.retries, 2);
 }
 
 #[tokio::test(flavor = "current_thread", start_paused = true)]
-async fn event_suppression_can_be_enabled_from_the_builder() {
-    let timer = Timer::once(Duration::from_secs(1))
+async fn set_retry_policy_applies_exponential_backoff_to_a_running_timer() {
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let attempts_for_callback = Arc::clone(&attempts);
+    let timer = Timer::new();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(1),
+            move || {
+                let attempts = Arc::clone(&attempts_for_callback);
+                async move {
+                    if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                        Err(TimerError::callback_failed("try again"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    let policy =
+        RetryPolicy::new(3).with_backoff(RetryBackoff::Exponential(Duration::from_millis(100)));
+    timer.set_retry_policy(Some(policy)).await.unwrap();
+    assert!(timer
+        .set_retry_policy(Some(
+            RetryPolicy::new(1).with_backoff(RetryBackoff::Fixed(Duration::ZERO))
+        ))
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+    advance(Duration::from_millis(100)).await;
+    settle().await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    advance(Duration::from_millis(100)).await;
+    settle().await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    advance(Duration::from_millis(100)).await;
+    settle().await;
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.statistics.execution_count, 1);
+    assert_eq!(outcome.statistics.failed_executions, 2);
+    assert_eq!(outcome.statistics.successful_executions, 1);
+    assert_eq!(outcome.statistics.retries, 2);
+    assert!(timer
+        .set_retry_policy(None)
+        .await
+        .unwrap_err()
+        .is_not_running());
+}
+
+async fn slow_fixed_rate_timer(policy: OverlapPolicy) -> (Timer, Arc<AtomicUsize>) {
+    let starts = Arc::new(AtomicUsize::new(0));
+    let starts_for_callback = Arc::clone(&starts);
+    let timer = Timer::new();
+    timer.set_overlap_policy(policy);
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).fixed_rate(),
+            move || {
+                let starts = Arc::clone(&starts_for_callback);
+                async move {
+                    starts.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(2_500)).await;
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    (timer, starts)
+}
+
+async fn advance_in_steps(total: Duration) {
+    let step = Duration::from_millis(500);
+    let mut elapsed = Duration::ZERO;
+    while elapsed < total {
+        advance(step).await;
+        settle().await;
+        elapsed += step;
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn queue_overlap_policy_runs_overdue_ticks_back_to_back() {
+    let (timer, starts) = slow_fixed_rate_timer(OverlapPolicy::Queue).await;
+    assert_eq!(timer.overlap_policy(), OverlapPolicy::Queue);
+
+    advance_in_steps(Duration::from_millis(3_500)).await;
+    assert_eq!(starts.load(Ordering::SeqCst), 2);
+    assert_eq!(timer.get_statistics().await.missed_executions, 0);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn skip_overlap_policy_drops_ticks_that_came_due_during_the_callback() {
+    let (timer, starts) = slow_fixed_rate_timer(OverlapPolicy::Skip).await;
+    assert_eq!(timer.overlap_policy(), OverlapPolicy::Skip);
+
+    advance_in_steps(Duration::from_millis(3_500)).await;
+    assert_eq!(starts.load(Ordering::SeqCst), 1);
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 1);
+    assert_eq!(statistics.missed_executions, 2);
+
+    advance_in_steps(Duration::from_millis(500)).await;
+    assert_eq!(starts.load(Ordering::SeqCst), 2);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn skip_overlap_policy_runs_ticks_that_are_late_within_tolerance() {
+    let starts = Arc::new(AtomicUsize::new(0));
+    let starts_for_callback = Arc::clone(&starts);
+    let timer = Timer::new();
+    timer.set_overlap_policy(OverlapPolicy::Skip);
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1))
+                .fixed_rate()
+                .with_tolerance(Duration::from_millis(50)),
+            move || {
+                let starts = Arc::clone(&starts_for_callback);
+                async move {
+                    starts.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(1_030)).await;
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    advance(Duration::from_millis(1_030)).await;
+    settle().await;
+
+    assert_eq!(starts.load(Ordering::SeqCst), 2);
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.missed_executions, 0);
+    assert_eq!(statistics.late_executions, 0);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn event_suppression_can_be_enabled_from_the_builder() {
+    let timer = Timer::once(Duration::from_secs(1))
         .with_events_disabled()
         .start(|| async { Ok(()) })
         .await
@@ -422,6 +1092,53 @@ async fn event_suppression_can_be_enabled_from_the_builder() {
     );
 }
 
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn every_subscriber_receives_the_full_lifecycle() {
+    let timer = Timer::new();
+    let mut subscribers = [timer.subscribe(), timer.subscribe()];
+
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2),
+            || async { Err(TimerError::callback_failed("boom")) },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    timer.pause().await.unwrap();
+    settle().await;
+    timer.resume().await.unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    timer.join().await.unwrap();
+
+    for events in &mut subscribers {
+        let mut kinds = Vec::new();
+        while let Some(event) = events.try_recv() {
+            kinds.push(match event {
+                TimerEvent::Started { .. } => "started",
+                TimerEvent::Tick { .. } => "tick",
+                TimerEvent::CallbackFailed { .. } => "failed",
+                TimerEvent::Paused { .. } => "paused",
+                TimerEvent::Resumed { .. } => "resumed",
+                TimerEvent::Finished(outcome) => {
+                    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+                    "finished"
+                }
+                _ => "other",
+            });
+        }
+        assert_eq!(
+            kinds,
+            ["started", "failed", "tick", "paused", "resumed", "failed", "tick", "finished"]
+        );
+        assert_eq!(events.lagged_events(), 0);
+    }
+}
+
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn event_helpers_wait_for_pause_resume_and_stop() {
     let timer = Timer::new();
@@ -452,6 +1169,37 @@ async fn event_helpers_wait_for_pause_resume_and_stop() {
     assert_eq!(seen, stopped);
 }
 
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn wait_for_state_resolves_when_timer_pauses() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(2)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    let waiter = {
+        let timer = timer.clone();
+        tokio::spawn(async move { timer.wait_for_state(TimerState::Paused).await })
+    };
+    settle().await;
+    assert!(!waiter.is_finished());
+
+    timer.pause().await.unwrap();
+    tokio::time::timeout(Duration::from_secs(1), waiter)
+        .await
+        .expect("waiter never saw the paused state")
+        .unwrap();
+
+    // Already in the target state, so this resolves immediately.
+    timer.wait_for_state(TimerState::Paused).await;
+
+    let _ = timer.cancel().await.unwrap();
+    timer.wait_for_state(TimerState::Stopped).await;
+}
+
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn event_helpers_wait_for_cancelled_outcomes() {
     let timer = Timer::new();
@@ -550,3 +1298,2556 @@ async fn fixed_rate_and_fixed_delay_schedules_diverge_under_slow_callbacks() {
         TimerFinishReason::Completed
     );
 }
+
+#[test]
+fn active_windows_can_wrap_around_midnight() {
+    let at = |hour| TimeOfDay::from_hms(hour, 0, 0).unwrap();
+
+    assert!(window_contains((at(9), at(17)), at(9)));
+    assert!(!window_contains((at(9), at(17)), at(17)));
+    assert!(window_contains((at(22), at(2)), at(23)));
+    assert!(window_contains((at(22), at(2)), at(1)));
+    assert!(!window_contains((at(22), at(2)), at(12)));
+    assert!(TimeOfDay::from_hms(24, 0, 0)
+        .unwrap_err()
+        .is_invalid_parameter());
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn active_window_skips_ticks_outside_the_daily_window() {
+    let (timer, runtime) = Timer::new_mocked();
+    runtime.set_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(7 * 3_600 + 1_800));
+
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(3_600)).with_active_window(
+                TimeOfDay::from_hms(9, 0, 0).unwrap(),
+                TimeOfDay::from_hms(11, 0, 0).unwrap(),
+            ),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap();
+    runtime.settle().await;
+
+    for _ in 0..4 {
+        runtime.advance(Duration::from_secs(3_600)).await;
+    }
+
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 2);
+    assert_eq!(statistics.skipped_executions, 2);
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[test]
+fn cron_expressions_find_the_next_utc_fire_time() {
+    let at = |seconds: u64| SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+    let weekdays_at_nine: CronSchedule = "0 9 * * mon-fri".parse().unwrap();
+    // Friday 2024-01-05 10:00 -> Monday 2024-01-08 09:00.
+    assert_eq!(
+        weekdays_at_nine.next_after(at(1_704_448_800)),
+        Some(at(1_704_704_400))
+    );
+    // 2024-01-01 00:07:30 -> 00:15.
+    assert_eq!(
+        CronSchedule::parse("*/15 * * * *")
+            .unwrap()
+            .next_after(at(1_704_067_650)),
+        Some(at(1_704_068_100))
+    );
+    // 2024-03-01 -> 2028-02-29.
+    assert_eq!(
+        CronSchedule::parse("0 0 29 feb *")
+            .unwrap()
+            .next_after(at(1_709_251_200)),
+        Some(at(1_835_395_200))
+    );
+    assert_eq!(
+        CronSchedule::parse("0 0 30 2 *")
+            .unwrap()
+            .next_after(at(1_709_251_200)),
+        None
+    );
+    assert_eq!(
+        CronSchedule::parse("0 0 * * 7").unwrap(),
+        CronSchedule::parse("0 0 * * sun").unwrap()
+    );
+
+    for invalid in [
+        "* * * *",
+        "60 * * * *",
+        "*/0 * * * *",
+        "5-1 * * * *",
+        "* * * foo *",
+    ] {
+        let error = CronSchedule::parse(invalid).unwrap_err();
+        assert!(error.is_invalid_parameter(), "{invalid}");
+        assert!(
+            error.invalid_parameter_message().unwrap().contains(invalid),
+            "{invalid}"
+        );
+    }
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn cron_timers_follow_the_wall_clock_and_can_be_paused() {
+    let (timer, runtime) = Timer::new_mocked();
+    // 2024-01-01 07:30 UTC.
+    runtime.set_system_time(SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_094_200));
+    assert!(timer
+        .start_cron("0 9 30 2 *", || async { Ok(()) })
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+
+    timer
+        .start_cron("0 * * * *", || async { Ok(()) })
+        .await
+        .unwrap();
+    runtime.settle().await;
+    assert_eq!(timer.get_interval().await, Duration::from_secs(3_600));
+    assert!(timer
+        .adjust_interval(Duration::from_secs(60))
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+    assert!(timer
+        .request_adjust_interval(Duration::from_secs(60))
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+
+    runtime.advance(Duration::from_secs(1_800)).await;
+    assert_eq!(timer.get_statistics().await.execution_count, 1);
+
+    timer.pause().await.unwrap();
+    runtime.settle().await;
+    runtime.advance(Duration::from_secs(2 * 3_600)).await;
+    timer.resume().await.unwrap();
+    runtime.settle().await;
+    // Resumed at 10:00; the ticks at 09:00 and 10:00 are not caught up.
+    runtime.advance(Duration::from_secs(3_599)).await;
+    assert_eq!(timer.get_statistics().await.execution_count, 1);
+    runtime.advance(Duration::from_secs(1)).await;
+    assert_eq!(timer.get_statistics().await.execution_count, 2);
+
+    let outcome = timer.stop().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Stopped);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn try_state_reads_the_state_from_sync_contexts() {
+    let timer = Timer::new();
+    assert_eq!(timer.try_state(), Some(TimerState::Stopped));
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    timer.pause().await.unwrap();
+
+    let observer = timer.clone();
+    let observed = std::thread::spawn(move || observer.try_state())
+        .join()
+        .unwrap();
+    assert_eq!(observed, Some(TimerState::Paused));
+
+    let _ = timer.cancel().await.unwrap();
+    assert_eq!(timer.try_state(), Some(TimerState::Stopped));
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn on_stop_runs_after_a_manual_stop() {
+    let stops = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .on_stop(CountingCallback {
+            executions: Arc::clone(&stops),
+            fail: false,
+        })
+        .await;
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(stops.load(Ordering::SeqCst), 0);
+
+    let outcome = timer.stop().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Stopped);
+    assert_eq!(stops.load(Ordering::SeqCst), 1);
+
+    timer
+        .start_once(Duration::from_secs(5), || async { Ok(()) })
+        .await
+        .unwrap();
+    let _ = timer.cancel().await.unwrap();
+    assert_eq!(stops.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn lifecycle_hooks_receive_a_context_for_the_finished_run() {
+    let seen = Arc::new(StdMutex::new(Vec::new()));
+    let timer = Timer::new();
+    for hook in ["complete", "stop"] {
+        let seen = Arc::clone(&seen);
+        let callback = ContextCallback::new(move |context: TimerContext| {
+            let seen = Arc::clone(&seen);
+            async move {
+                seen.lock()
+                    .unwrap()
+                    .push((hook, context.run_id(), context.execution()));
+                Ok(())
+            }
+        });
+        if hook == "complete" {
+            timer.on_complete(callback).await;
+        } else {
+            timer.on_stop(callback).await;
+        }
+    }
+
+    let run_id = timer
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    timer.join().await.unwrap();
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![("complete", run_id, 1), ("stop", run_id, 1)]
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn on_complete_runs_only_when_a_run_finishes_on_its_own() {
+    let completions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .on_complete(CountingCallback {
+            executions: Arc::clone(&completions),
+            fail: false,
+        })
+        .await;
+
+    timer
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    assert_eq!(completions.load(Ordering::SeqCst), 1);
+
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    assert_eq!(completions.load(Ordering::SeqCst), 2);
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(
+        timer.stop().await.unwrap().reason,
+        TimerFinishReason::Stopped
+    );
+
+    timer
+        .start_once(Duration::from_secs(5), || async { Ok(()) })
+        .await
+        .unwrap();
+    let _ = timer.cancel().await.unwrap();
+    assert_eq!(completions.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn soft_and_hard_timeouts_are_counted_separately() {
+    let durations = Arc::new(StdMutex::new(vec![10, 3]));
+    let durations_for_callback = Arc::clone(&durations);
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2))
+            .soft_timeout(Duration::from_secs(2))
+            .hard_timeout(Duration::from_secs(5))
+            .start(move || {
+                let seconds = durations_for_callback.lock().unwrap().pop().unwrap();
+                async move {
+                    tokio::time::sleep(Duration::from_secs(seconds)).await;
+                    Ok::<(), TimerError>(())
+                }
+            })
+            .await
+            .unwrap();
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.statistics.soft_timeouts, 1);
+    assert_eq!(outcome.statistics.hard_timeouts, 1);
+    assert_eq!(outcome.statistics.successful_executions, 1);
+    assert_eq!(outcome.statistics.failed_executions, 1);
+    assert!(durations.lock().unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn soft_timeout_must_be_shorter_than_the_hard_timeout() {
+    let error = Timer::once(Duration::from_secs(1))
+        .soft_timeout(Duration::from_secs(5))
+        .hard_timeout(Duration::from_secs(5))
+        .start(|| async { Ok(()) })
+        .await
+        .err()
+        .unwrap();
+    assert!(error.is_invalid_parameter());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn execution_history_is_preallocated_and_bounded() {
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(5))
+            .with_history_capacity(3)
+            .start(|| async { Ok(()) })
+            .await
+            .unwrap();
+    let preallocated = timer.inner.history.lock().await.capacity();
+    assert!(preallocated >= 3);
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.statistics.execution_count, 5);
+
+    let history = timer.execution_history().await;
+    assert_eq!(
+        history
+            .iter()
+            .map(|record| record.execution)
+            .collect::<Vec<_>>(),
+        vec![3, 4, 5]
+    );
+    assert!(history.iter().all(|record| record.succeeded));
+    assert_eq!(timer.inner.history.lock().await.capacity(), preallocated);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn full_event_buffer_holds_back_ticks_without_losing_events() {
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .pause_on_full_events(true)
+        .start(|| async { Ok(()) })
+        .await
+        .unwrap();
+    let mut events = timer.subscribe();
+    settle().await;
+
+    for _ in 0..100 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let held_back_at = timer.get_statistics().await.execution_count;
+    assert_eq!(held_back_at, TIMER_EVENT_BUFFER - EVENTS_PER_TICK + 1);
+
+    let mut seen = Vec::new();
+    while let Some(event) = events.try_recv() {
+        if let TimerEvent::Tick { statistics, .. } = event {
+            seen.push(statistics.execution_count);
+        }
+    }
+    assert_eq!(seen, (1..=held_back_at).collect::<Vec<_>>());
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert!(timer.get_statistics().await.execution_count > held_back_at);
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn held_back_ticks_resume_as_soon_as_subscribers_free_room() {
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .pause_on_full_events(true)
+        .start(|| async { Ok(()) })
+        .await
+        .unwrap();
+    let mut events = timer.subscribe();
+    settle().await;
+
+    for _ in 0..TIMER_EVENT_BUFFER {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    let held_back_at = timer.get_statistics().await.execution_count;
+
+    events.try_recv().unwrap();
+    settle().await;
+    assert_eq!(
+        timer.get_statistics().await.execution_count,
+        held_back_at + 1
+    );
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(
+        timer.get_statistics().await.execution_count,
+        held_back_at + 1
+    );
+    drop(events);
+    settle().await;
+    assert_eq!(
+        timer.get_statistics().await.execution_count,
+        held_back_at + 2
+    );
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn timer_config_describes_one_shots_and_rejects_their_expiration_count() {
+    let once = TimerConfig {
+        interval: Duration::from_secs(1),
+        recurring: false,
+        expiration_count: None,
+        name: None,
+    };
+    let timer = Timer::from_config(&once, || async { Ok(()) })
+        .await
+        .unwrap();
+    assert_eq!(timer.to_config().await, once);
+    assert!(!Timer::new().to_config().await.recurring);
+
+    let invalid = TimerConfig {
+        expiration_count: Some(2),
+        ..once
+    };
+    let error = Timer::from_config(&invalid, || async { Ok(()) })
+        .await
+        .err()
+        .unwrap();
+    assert!(error.is_invalid_parameter());
+    timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn duplicated_config_runs_independently_of_the_original() {
+    let original_runs = Arc::new(AtomicUsize::new(0));
+    let duplicate_runs = Arc::new(AtomicUsize::new(0));
+    let original =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(2)).with_expiration_count(3))
+            .label("poller")
+            .start(CountingCallback {
+                executions: Arc::clone(&original_runs),
+                fail: false,
+            })
+            .await
+            .unwrap();
+
+    let duplicate = original.duplicate_config().await;
+    assert_eq!(duplicate.get_state().await, TimerState::Stopped);
+    assert_eq!(duplicate.get_interval().await, Duration::from_secs(2));
+    assert_eq!(duplicate.get_expiration_count().await, Some(3));
+    assert_eq!(duplicate.label().await.as_deref(), Some("poller"));
+
+    duplicate
+        .restart_with(CountingCallback {
+            executions: Arc::clone(&duplicate_runs),
+            fail: false,
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(2)).await;
+    settle().await;
+    let _ = original.cancel().await.unwrap();
+
+    let outcome = duplicate.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert_eq!(duplicate_runs.load(Ordering::SeqCst), 3);
+    assert_eq!(original_runs.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        original.last_outcome().await.unwrap().reason,
+        TimerFinishReason::Cancelled
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn one_shot_outcome_reports_fired_runs() {
+    let timer = Timer::new();
+    assert_eq!(timer.one_shot_outcome().await, None);
+
+    timer
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    assert_eq!(
+        timer.one_shot_outcome().await,
+        Some(OneShotOutcome::Pending)
+    );
+    settle().await;
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(timer.one_shot_outcome().await, Some(OneShotOutcome::Fired));
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn one_shot_outcome_does_not_depend_on_statistics() {
+    let unrecorded = Timer::once(Duration::from_secs(1))
+        .stats_level(StatsLevel::Off)
+        .start(|| async { Ok(()) })
+        .await
+        .unwrap();
+    let reset = Timer::new();
+    reset
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(reset.take_statistics().await.execution_count, 1);
+    assert_eq!(reset.get_statistics().await.execution_count, 0);
+
+    for timer in [unrecorded, reset] {
+        assert_eq!(timer.one_shot_outcome().await, Some(OneShotOutcome::Fired));
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn one_shot_outcome_reports_runs_stopped_before_firing() {
+    let timer = Timer::new();
+    timer
+        .start_once(Duration::from_secs(5), || async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+
+    let outcome = timer.stop().await.unwrap();
+    assert_eq!(outcome.statistics.execution_count, 0);
+    assert_eq!(
+        timer.one_shot_outcome().await,
+        Some(OneShotOutcome::Cancelled)
+    );
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    assert_eq!(timer.one_shot_outcome().await, None);
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn execution_duration_extremes_and_average_are_tracked() {
+    let durations = Arc::new(StdMutex::new(vec![2, 5, 1]));
+    let durations_for_callback = Arc::clone(&durations);
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(3))
+            .start(move || {
+                let seconds = durations_for_callback.lock().unwrap().pop().unwrap();
+                async move {
+                    tokio::time::sleep(Duration::from_secs(seconds)).await;
+                    Ok::<(), TimerError>(())
+                }
+            })
+            .await
+            .unwrap();
+
+    let statistics = timer.join().await.unwrap().statistics;
+    assert_eq!(statistics.min_execution_duration, Duration::from_secs(1));
+    assert_eq!(statistics.max_execution_duration, Duration::from_secs(5));
+    assert_eq!(statistics.total_execution_duration, Duration::from_secs(8));
+    assert_eq!(
+        statistics.average_execution_duration(),
+        Duration::from_secs(8) / 3
+    );
+    assert_eq!(
+        TimerStatistics::default().average_execution_duration(),
+        Duration::ZERO
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn take_statistics_never_loses_or_duplicates_executions() {
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(50))
+            .start(|| async {
+                yield_now().await;
+                Ok::<(), TimerError>(())
+            })
+            .await
+            .unwrap();
+
+    let taker = {
+        let timer = timer.clone();
+        tokio::spawn(async move {
+            let mut taken = 0;
+            for _ in 0..20 {
+                taken += timer.take_statistics().await.execution_count;
+                tokio::time::sleep(Duration::from_millis(1_700)).await;
+            }
+            taken
+        })
+    };
+
+    let outcome = timer.join().await.unwrap();
+    let taken = taker.await.unwrap();
+    let remaining = timer.take_statistics().await;
+
+    assert_eq!(taken + remaining.execution_count, 50);
+    assert_eq!(
+        remaining.execution_count,
+        outcome.statistics.execution_count
+    );
+    assert_eq!(timer.get_statistics().await.execution_count, 0);
+    assert_eq!(
+        timer.get_statistics().await.elapsed_time,
+        remaining.elapsed_time
+    );
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn fixed_rate_deadlines_do_not_drift_over_many_ticks() {
+    const TICKS: u32 = 10_000;
+    let interval = Duration::new(0, 333_333_333);
+    let (timer, runtime) = Timer::new_mocked();
+    let fires = Arc::new(StdMutex::new(Vec::new()));
+    let origin = runtime.now();
+
+    let fires_for_callback = Arc::clone(&fires);
+    let clock = runtime.clone();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(interval)
+                .fixed_rate()
+                .with_expiration_count(TICKS as usize),
+            move || {
+                let fires = Arc::clone(&fires_for_callback);
+                let now = clock.now();
+                async move {
+                    fires.lock().unwrap().push(now);
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    runtime.settle().await;
+
+    for _ in 0..TICKS {
+        runtime.advance(interval).await;
+    }
+
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    let fires = fires.lock().unwrap();
+    assert_eq!(fires.len(), TICKS as usize);
+    for (index, fired_at) in fires.iter().enumerate() {
+        assert_eq!(*fired_at, origin + interval * (index as u32 + 1));
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn fixed_rate_absorbs_callback_overhead_that_fixed_delay_accumulates() {
+    for (schedule, expected_last_start) in [
+        (
+            RecurringSchedule::new(Duration::from_millis(100)).fixed_rate(),
+            Duration::from_millis(1_000),
+        ),
+        (
+            RecurringSchedule::new(Duration::from_millis(100)).fixed_delay(),
+            Duration::from_millis(1_270),
+        ),
+    ] {
+        let started_at = Instant::now();
+        let last_start = Arc::new(StdMutex::new(Duration::ZERO));
+        let recorded = Arc::clone(&last_start);
+        let timer = Timer::recurring(schedule.with_expiration_count(10))
+            .start(move || {
+                let recorded = Arc::clone(&recorded);
+                async move {
+                    *recorded.lock().unwrap() = started_at.elapsed();
+                    tokio::time::sleep(Duration::from_millis(30)).await;
+                    Ok(())
+                }
+            })
+            .await
+            .unwrap();
+
+        let outcome = timer.join().await.unwrap();
+        assert_eq!(outcome.statistics.execution_count, 10);
+        assert_eq!(*last_start.lock().unwrap(), expected_last_start);
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn last_result_reflects_the_most_recent_tick() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                if executions.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Ok(())
+                } else {
+                    Err(TimerError::callback_failed("second tick fails"))
+                }
+            }
+        })
+        .await
+        .unwrap();
+    assert_eq!(timer.last_result().await, None);
+    settle().await;
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(timer.last_result().await, Some(Ok(())));
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(
+        timer.last_result().await,
+        Some(Err(TimerError::callback_failed("second tick fails")))
+    );
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn inter_arrival_times_track_the_interval_between_executions() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..4 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 4);
+    assert_eq!(statistics.inter_arrival_times.len(), 3);
+    for delta in statistics.inter_arrival_times {
+        assert!(delta >= Duration::from_millis(999) && delta <= Duration::from_millis(1_001));
+    }
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[cfg(feature = "test-util")]
+async fn executions_around_long_pause(policy: ResumeCatchUp) -> (usize, usize) {
+    let (timer, runtime) = Timer::new_mocked();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(10)).with_resume_catch_up(policy),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap();
+    runtime.settle().await;
+    runtime.advance(Duration::from_secs(10)).await;
+    assert_eq!(timer.get_statistics().await.execution_count, 1);
+
+    timer.pause().await.unwrap();
+    runtime.settle().await;
+    runtime.advance(Duration::from_secs(55)).await;
+    timer.resume().await.unwrap();
+    for _ in 0..4 {
+        runtime.settle().await;
+    }
+    let after_resume = timer.get_statistics().await.execution_count;
+
+    runtime.advance(Duration::from_secs(10)).await;
+    let after_next_interval = timer.get_statistics().await.execution_count;
+
+    let _ = timer.cancel().await.unwrap();
+    (after_resume, after_next_interval)
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn resume_catch_up_continue_on_schedule_skips_missed_intervals() {
+    assert_eq!(
+        executions_around_long_pause(ResumeCatchUp::ContinueOnSchedule).await,
+        (1, 2)
+    );
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn resume_catch_up_fire_once_fires_immediately_after_resuming() {
+    assert_eq!(
+        executions_around_long_pause(ResumeCatchUp::FireOnce).await,
+        (2, 3)
+    );
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn resume_catch_up_fire_missed_is_bounded() {
+    assert_eq!(
+        executions_around_long_pause(ResumeCatchUp::FireMissed(3)).await,
+        (4, 5)
+    );
+    assert_eq!(
+        executions_around_long_pause(ResumeCatchUp::FireMissed(10)).await,
+        (6, 7)
+    );
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn drift_histogram_accumulates_samples_per_tick() {
+    let (timer, runtime) = Timer::new_mocked();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    runtime.settle().await;
+
+    for _ in 0..3 {
+        runtime.advance(Duration::from_secs(1)).await;
+    }
+    runtime.advance(Duration::from_millis(1_200)).await;
+
+    let histogram = timer.drift_histogram().await;
+    assert_eq!(histogram.count(), 4);
+    assert_eq!(histogram.max(), Duration::from_millis(200));
+    let buckets = histogram.buckets();
+    assert_eq!(buckets[0], (Some(Duration::from_millis(1)), 3));
+    assert_eq!(buckets[5], (Some(Duration::from_millis(500)), 1));
+    assert_eq!(buckets.last(), Some(&(None, 0)));
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn leadership_gate_only_executes_while_leader() {
+    let leader = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let leader_for_gate = Arc::clone(&leader);
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .leadership(Arc::new(move || leader_for_gate.load(Ordering::SeqCst)))
+        .start(move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    for leading in [false, false, true, true, false] {
+        leader.store(leading, Ordering::SeqCst);
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let statistics = timer.get_statistics().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 2);
+    assert_eq!(statistics.execution_count, 2);
+    assert_eq!(statistics.follower_skips, 3);
+    assert_eq!(statistics.skipped_executions, 0);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn enabled_flag_skips_ticks_without_pausing_the_schedule() {
+    let enabled = Arc::new(AtomicBool::new(true));
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .with_enabled_flag(Arc::clone(&enabled))
+        .start(move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    let mut expected = 0;
+    for on in [true, false, false, true, false, true] {
+        enabled.store(on, Ordering::SeqCst);
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        expected += usize::from(on);
+        assert_eq!(executions.load(Ordering::SeqCst), expected);
+        assert_eq!(timer.get_state().await, TimerState::Running);
+    }
+
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 3);
+    assert_eq!(statistics.skipped_executions, 3);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn slow_subscribers_report_lag_and_keep_event_order() {
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .event_capacity(4)
+        .start(|| async { Ok(()) })
+        .await
+        .unwrap();
+    let mut events = timer.subscribe();
+    settle().await;
+
+    for _ in 0..6 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let mut execution_counts = Vec::new();
+    while let Some(event) = events.try_recv() {
+        if let TimerEvent::Tick { statistics, .. } = event {
+            execution_counts.push(statistics.execution_count);
+        }
+    }
+
+    assert_eq!(events.lagged_events(), 2);
+    assert_eq!(execution_counts, vec![3, 4, 5, 6]);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test]
+async fn zero_event_capacity_is_rejected() {
+    let error = Timer::once(Duration::from_secs(1))
+        .event_capacity(0)
+        .start(|| async { Ok(()) })
+        .await
+        .err()
+        .unwrap();
+    assert!(error.is_invalid_parameter());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn join_sees_the_outcome_of_a_run_that_is_still_finishing() {
+    let timer = Timer::new();
+    timer
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+
+    // Holding the state lock stalls the run partway through finishing, after
+    // its run id was cleared.
+    let state = timer.inner.state.lock().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(timer.inner.active_run_id.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+
+    drop(state);
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Completed);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn finalizer_runs_after_expiration_count_completion() {
+    let finalized = Arc::new(AtomicUsize::new(0));
+    let finalized_for_hook = Arc::clone(&finalized);
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2))
+            .finalizer(move || {
+                finalized_for_hook.fetch_add(1, Ordering::SeqCst);
+            })
+            .start(|| async { Ok(()) })
+            .await
+            .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(finalized.load(Ordering::SeqCst), 0);
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    settle().await;
+    assert_eq!(finalized.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn finalizer_runs_once_per_timer_across_restarts() {
+    let finalized = Arc::new(AtomicUsize::new(0));
+    let finalized_for_hook = Arc::clone(&finalized);
+    let timer = Timer::once(Duration::from_secs(1))
+        .finalizer(move || {
+            finalized_for_hook.fetch_add(1, Ordering::SeqCst);
+        })
+        .start(|| async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+    timer.stop().await.unwrap();
+    settle().await;
+    assert_eq!(finalized.load(Ordering::SeqCst), 1);
+
+    timer.restart().await.unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    settle().await;
+    assert_eq!(finalized.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn is_task_alive_detects_an_externally_aborted_task() {
+    let timer = Timer::new();
+    assert!(!timer.is_task_alive().await);
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    assert!(timer.is_task_alive().await);
+
+    timer.inner.handle.lock().await.as_ref().unwrap().abort();
+    settle().await;
+
+    assert!(!timer.is_task_alive().await);
+    assert_eq!(timer.get_state().await, TimerState::Running);
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn fixed_rate_tolerance_ignores_small_delays() {
+    let (timer, runtime) = Timer::new_mocked();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1))
+                .fixed_rate()
+                .with_tolerance(Duration::from_millis(50)),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap();
+    runtime.settle().await;
+
+    for step in [1_020, 1_010, 970] {
+        runtime.advance(Duration::from_millis(step)).await;
+    }
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 3);
+    assert_eq!(statistics.late_executions, 0);
+
+    runtime.advance(Duration::from_millis(1_100)).await;
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 4);
+    assert_eq!(statistics.late_executions, 1);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn stats_level_off_records_nothing() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .stats_level(StatsLevel::Off)
+        .with_history_capacity(4)
+        .start(move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    assert_eq!(executions.load(Ordering::SeqCst), 3);
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics, TimerStatistics::default());
+    assert_eq!(statistics.inter_arrival_times.capacity(), 0);
+    assert!(timer.execution_history().await.is_empty());
+    assert_eq!(timer.drift_histogram().await.count(), 0);
+    assert_eq!(timer.last_result().await, None);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn stats_level_counters_only_tracks_counts() {
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .stats_level(StatsLevel::Counters)
+        .start(|| async { Err(TimerError::callback_failed("counted")) })
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let statistics = timer.get_statistics().await;
+    assert_eq!(statistics.execution_count, 3);
+    assert_eq!(statistics.failed_executions, 3);
+    assert_eq!(statistics.last_error, None);
+    assert!(statistics.inter_arrival_times.is_empty());
+    assert_eq!(timer.drift_histogram().await.count(), 0);
+
+    assert_eq!(timer.take_statistics().await.execution_count, 3);
+    assert_eq!(timer.get_statistics().await.execution_count, 0);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn callback_group_runs_callbacks_in_priority_order() {
+    let order = Arc::new(StdMutex::new(Vec::new()));
+    let recorder = |label: &'static str| {
+        let order = Arc::clone(&order);
+        move || {
+            let order = Arc::clone(&order);
+            async move {
+                order.lock().unwrap().push(label);
+                Ok(())
+            }
+        }
+    };
+    let group = CallbackGroup::new()
+        .add_callback_with_priority(recorder("low"), -1)
+        .add_callback(recorder("default-first"))
+        .add_callback_with_priority(recorder("high"), 10)
+        .add_callback(recorder("default-second"));
+    assert_eq!(group.len(), 4);
+
+    let timer = Timer::new();
+    timer
+        .start_once(Duration::from_secs(1), group)
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec!["high", "default-first", "default-second", "low"]
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn callback_group_stops_at_first_failure() {
+    let later_runs = Arc::new(AtomicUsize::new(0));
+    let later_runs_for_callback = Arc::clone(&later_runs);
+    let group = CallbackGroup::new()
+        .add_callback_with_priority(
+            || async { Err(TimerError::callback_failed("prepare failed")) },
+            1,
+        )
+        .add_callback(move || {
+            let later_runs = Arc::clone(&later_runs_for_callback);
+            async move {
+                later_runs.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        });
+
+    assert_eq!(
+        group.execute().await,
+        Err(TimerError::callback_failed("prepare failed"))
+    );
+    assert_eq!(later_runs.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn circuit_breaker_opens_half_opens_and_closes() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let failing = Arc::new(AtomicBool::new(true));
+    let callback = {
+        let calls = Arc::clone(&calls);
+        let failing = Arc::clone(&failing);
+        move || {
+            let calls = Arc::clone(&calls);
+            let failing = Arc::clone(&failing);
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                if failing.load(Ordering::SeqCst) {
+                    Err(TimerError::callback_failed("upstream down"))
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    };
+    let breaker = CircuitBreaker::new(callback, 2, Duration::from_secs(5)).unwrap();
+
+    assert!(breaker.execute().await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert!(breaker.execute().await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    // Open: calls are skipped and counted.
+    assert_eq!(breaker.execute().await, Ok(()));
+    assert_eq!(breaker.execute().await, Ok(()));
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(breaker.short_circuited_calls(), 2);
+
+    // A failed trial after the cooldown reopens the circuit.
+    advance(Duration::from_secs(5)).await;
+    assert!(breaker.execute().await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+    // A successful trial closes it again.
+    failing.store(false, Ordering::SeqCst);
+    advance(Duration::from_secs(5)).await;
+    assert_eq!(breaker.execute().await, Ok(()));
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    assert_eq!(calls.load(Ordering::SeqCst), 4);
+    assert_eq!(breaker.short_circuited_calls(), 2);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn circuit_breaker_reports_half_open_during_trial() {
+    type StateProbe = Box<dyn Fn() -> CircuitState + Send>;
+
+    let observed = Arc::new(StdMutex::new(None));
+    let probe: Arc<StdMutex<Option<StateProbe>>> = Arc::new(StdMutex::new(None));
+    let callback = {
+        let observed = Arc::clone(&observed);
+        let probe = Arc::clone(&probe);
+        move || {
+            let observed = Arc::clone(&observed);
+            let probe = Arc::clone(&probe);
+            async move {
+                *observed.lock().unwrap() = probe.lock().unwrap().as_ref().map(|probe| probe());
+                Err(TimerError::callback_failed("still down"))
+            }
+        }
+    };
+    let breaker = CircuitBreaker::new(callback, 1, Duration::from_secs(1)).unwrap();
+    let observer = breaker.clone();
+    *probe.lock().unwrap() = Some(Box::new(move || observer.state()));
+
+    assert!(breaker.execute().await.is_err());
+    assert_eq!(breaker.state(), CircuitState::Open);
+
+    advance(Duration::from_secs(1)).await;
+    assert!(breaker.execute().await.is_err());
+    assert_eq!(*observed.lock().unwrap(), Some(CircuitState::HalfOpen));
+    assert_eq!(breaker.state(), CircuitState::Open);
+    // Break the breaker -> probe -> breaker cycle.
+    probe.lock().unwrap().take();
+}
+
+#[test]
+fn circuit_breaker_rejects_zero_threshold() {
+    assert!(CircuitBreaker::new(
+        || async { Ok::<(), TimerError>(()) },
+        0,
+        Duration::from_secs(1)
+    )
+    .err()
+    .unwrap()
+    .is_invalid_parameter());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn hybrid_timer_fires_on_trigger_and_resets_interval() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let (trigger_tx, trigger_rx) = mpsc::channel(4);
+    let timer = Timer::new();
+    timer
+        .start_hybrid(Duration::from_secs(10), trigger_rx, move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_secs(4)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 0);
+
+    trigger_tx.send(()).await.unwrap();
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+
+    // The interval restarted at the trigger, so the original deadline passes quietly.
+    advance(Duration::from_secs(9)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 2);
+
+    let _ = timer.cancel().await.unwrap();
+    settle().await;
+    assert!(trigger_tx.is_closed());
+}
+
+#[cfg(feature = "serde")]
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn timer_config_round_trips_through_json_into_an_equivalent_timer() {
+    let config = TimerConfig {
+        interval: Duration::from_secs(2),
+        recurring: true,
+        expiration_count: Some(3),
+        name: Some("backup".to_string()),
+    };
+
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(json["interval"], 2_000);
+    let restored: TimerConfig = serde_json::from_value(json).unwrap();
+    assert_eq!(restored, config);
+
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::from_config(
+        &restored,
+        CountingCallback {
+            executions: Arc::clone(&executions),
+            fail: false,
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(timer.to_config().await, config);
+    settle().await;
+
+    for _ in 0..3 {
+        advance(Duration::from_secs(2)).await;
+        settle().await;
+    }
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(executions.load(Ordering::SeqCst), 3);
+
+    let minimal: TimerConfig =
+        serde_json::from_value(serde_json::json!({ "interval": 500, "recurring": false })).unwrap();
+    assert_eq!(minimal.expiration_count, None);
+    assert_eq!(minimal.name, None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn statistics_round_trip_through_json() {
+    let statistics = TimerStatistics {
+        execution_count: 5,
+        successful_executions: 3,
+        failed_executions: 2,
+        retries: 1,
+        skipped_executions: 1,
+        missed_executions: 1,
+        follower_skips: 1,
+        late_executions: 1,
+        soft_timeouts: 1,
+        hard_timeouts: 1,
+        min_execution_duration: Duration::from_millis(2),
+        max_execution_duration: Duration::from_millis(40),
+        total_execution_duration: Duration::from_millis(75),
+        elapsed_time: Duration::from_secs(12),
+        inter_arrival_times: vec![Duration::from_millis(1_000), Duration::from_millis(1_250)],
+        last_error: Some(TimerError::callback_timed_out(Duration::from_millis(500))),
+    };
+
+    let json = serde_json::to_value(&statistics).unwrap();
+    assert_eq!(json["min_execution_duration"], 2);
+    assert_eq!(json["elapsed_time"], 12_000);
+    assert_eq!(
+        json["inter_arrival_times"],
+        serde_json::json!([1_000, 1_250])
+    );
+
+    let restored: TimerStatistics = serde_json::from_value(json).unwrap();
+    assert_eq!(restored, statistics);
+    assert_eq!(
+        restored.last_error.unwrap().callback_timeout(),
+        Some(Duration::from_millis(500))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn statistics_missing_newer_counters_deserialize_with_defaults() {
+    let restored: TimerStatistics = serde_json::from_value(serde_json::json!({
+        "execution_count": 4,
+        "successful_executions": 3,
+        "failed_executions": 1,
+        "elapsed_time": 4_000,
+        "last_error": null,
+    }))
+    .unwrap();
+
+    assert_eq!(
+        restored,
+        TimerStatistics {
+            execution_count: 4,
+            successful_executions: 3,
+            failed_executions: 1,
+            elapsed_time: Duration::from_secs(4),
+            ..TimerStatistics::default()
+        }
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn stop_cancels_the_context_token_for_cooperative_callbacks() {
+    let exited_cleanly = Arc::new(AtomicBool::new(false));
+    let exited_for_callback = Arc::clone(&exited_cleanly);
+    let timer = Timer::new();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)),
+            ContextCallback::new(move |context: TimerContext| {
+                let exited_cleanly = Arc::clone(&exited_for_callback);
+                async move {
+                    let token = context.cancellation_token();
+                    assert!(!token.is_cancelled());
+                    tokio::select! {
+                        _ = token.cancelled() => exited_cleanly.store(true, Ordering::SeqCst),
+                        _ = tokio::time::sleep(Duration::from_secs(3_600)) => {}
+                    }
+                    Ok(())
+                }
+            }),
+        )
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert!(!exited_cleanly.load(Ordering::SeqCst));
+
+    let outcome = timer.stop().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Stopped);
+    assert!(exited_cleanly.load(Ordering::SeqCst));
+    assert_eq!(outcome.statistics.successful_executions, 1);
+}
+
+async fn run_flaky_timer_to_expiration(count_errors_toward_limit: bool) -> TimerOutcome {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::recurring(
+        RecurringSchedule::new(Duration::from_secs(1))
+            .with_expiration_count(3)
+            .with_count_errors_toward_limit(count_errors_toward_limit),
+    )
+    .start(move || {
+        let calls = Arc::clone(&calls);
+        async move {
+            // Every odd call fails.
+            if calls.fetch_add(1, Ordering::SeqCst) % 2 == 0 {
+                Err(TimerError::callback_failed("flaky"))
+            } else {
+                Ok(())
+            }
+        }
+    })
+    .await
+    .unwrap();
+    settle().await;
+
+    for _ in 0..10 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    timer.join().await.unwrap()
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn expiration_count_includes_failed_executions_by_default() {
+    let outcome = run_flaky_timer_to_expiration(true).await;
+
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert_eq!(outcome.statistics.successful_executions, 1);
+    assert_eq!(outcome.statistics.failed_executions, 2);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn expiration_count_can_count_only_successful_executions() {
+    let outcome = run_flaky_timer_to_expiration(false).await;
+
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 6);
+    assert_eq!(outcome.statistics.successful_executions, 3);
+    assert_eq!(outcome.statistics.failed_executions, 3);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn set_metadata_is_reported_in_events_and_snapshots() {
+    let timer = Timer::new();
+    let mut events = timer.subscribe();
+    let metadata = TimerMetadata {
+        label: Some("invoice-reminder".to_string()),
+        tags: BTreeMap::from([("invoice".to_string(), "INV-42".to_string())]),
+    };
+    timer.set_metadata(metadata.clone()).await;
+    assert_eq!(timer.metadata().await, metadata);
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    match events.recv().await {
+        Some(TimerEvent::Started {
+            metadata: started, ..
+        }) => assert_eq!(started, metadata),
+        other => panic!("expected a started event, got {other:?}"),
+    }
+    assert_eq!(timer.snapshot().await.metadata, metadata);
+
+    timer.set_metadata(TimerMetadata::default()).await;
+    assert_eq!(timer.snapshot().await.metadata, TimerMetadata::default());
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn next_fire_at_reports_the_upcoming_wall_clock_deadline() {
+    let timer = Timer::new();
+    assert_eq!(timer.next_fire_at().await, None);
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(5)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(2)).await;
+
+    let before = SystemTime::now();
+    let next_fire = timer.next_fire_at().await.unwrap();
+    let after = SystemTime::now();
+    assert!(next_fire >= before + Duration::from_secs(3));
+    assert!(next_fire <= after + Duration::from_secs(3));
+
+    timer.pause().await.unwrap();
+    assert_eq!(timer.next_fire_at().await, None);
+    timer.resume().await.unwrap();
+    settle().await;
+    assert!(timer.next_fire_at().await.unwrap() > SystemTime::now());
+
+    let _ = timer.cancel().await.unwrap();
+    assert_eq!(timer.next_fire_at().await, None);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn adjusting_interval_to_max_parks_until_adjusted_back() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)))
+        .start(move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+
+    timer.adjust_interval(Duration::MAX).await.unwrap();
+    settle().await;
+    for _ in 0..5 {
+        advance(Duration::from_secs(86_400 * 365)).await;
+        settle().await;
+    }
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+    assert_eq!(timer.get_state().await, TimerState::Running);
+    assert_eq!(timer.get_interval().await, Duration::MAX);
+
+    timer.adjust_interval(Duration::from_secs(1)).await.unwrap();
+    settle().await;
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    assert_eq!(executions.load(Ordering::SeqCst), 3);
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn error_handler_receives_each_callback_error() {
+    let seen = Arc::new(StdMutex::new(Vec::new()));
+    let seen_for_handler = Arc::clone(&seen);
+    let timer = Timer::new();
+    timer
+        .set_error_handler(move |err| seen_for_handler.lock().unwrap().push(err.clone()))
+        .await;
+
+    let attempts = Arc::new(AtomicUsize::new(0));
+    timer
+        .start_once(Duration::from_secs(1), move || {
+            let attempts = Arc::clone(&attempts);
+            async move {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+                Err(TimerError::callback_failed(format!("attempt {attempt}")))
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.statistics.failed_executions, 1);
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![TimerError::callback_failed("attempt 1")]
+    );
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn countdown_runs_ticks_then_the_final_callback() {
+    let calls = Arc::new(StdMutex::new(Vec::new()));
+    let recorder = |label: &'static str| {
+        let calls = Arc::clone(&calls);
+        move || {
+            let calls = Arc::clone(&calls);
+            async move {
+                calls.lock().unwrap().push(label);
+                Ok(())
+            }
+        }
+    };
+    let timer = Timer::new();
+    timer
+        .start_countdown(Duration::from_secs(1), 3, recorder("tick"), recorder("go"))
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..4 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert_eq!(*calls.lock().unwrap(), vec!["tick", "tick", "go"]);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn rate_targeted_timer_converges_on_the_target_rate() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    let counter = Arc::clone(&executions);
+    timer
+        .start_rate_targeted(
+            12,
+            Duration::from_secs(1),
+            Duration::from_secs(10),
+            move || {
+                let counter = Arc::clone(&counter);
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_secs(2)).await;
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    let run_for = |duration: Duration| async move {
+        for _ in 0..duration.as_millis() / 250 {
+            advance(Duration::from_millis(250)).await;
+            settle().await;
+        }
+    };
+    run_for(Duration::from_secs(180)).await;
+    let before = executions.load(Ordering::SeqCst);
+    run_for(Duration::from_secs(60)).await;
+    let per_minute = executions.load(Ordering::SeqCst) - before;
+
+    assert!((11..=13).contains(&per_minute), "{per_minute} per minute");
+    let interval = timer.get_interval().await;
+    assert!(
+        interval >= Duration::from_millis(2_500) && interval <= Duration::from_millis(3_500),
+        "{interval:?}"
+    );
+    timer.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn rate_targeted_timer_rejects_invalid_bounds() {
+    let timer = Timer::new();
+    let callback = || async { Ok(()) };
+    assert!(timer
+        .start_rate_targeted(0, Duration::from_secs(1), Duration::from_secs(2), callback)
+        .await
+        .is_err());
+    assert!(timer
+        .start_rate_targeted(10, Duration::from_secs(3), Duration::from_secs(2), callback)
+        .await
+        .is_err());
+    assert!(timer
+        .start_rate_targeted(10, Duration::ZERO, Duration::from_secs(2), callback)
+        .await
+        .is_err());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn decaying_timer_intervals_grow_geometrically_up_to_the_ceiling() {
+    let started_at = Instant::now();
+    let fires = Arc::new(StdMutex::new(Vec::new()));
+    let recorded = Arc::clone(&fires);
+    let timer = Timer::new();
+    timer
+        .start_decaying(
+            Duration::from_secs(1),
+            2.0,
+            Duration::from_secs(8),
+            move || {
+                let recorded = Arc::clone(&recorded);
+                async move {
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push(started_at.elapsed().as_secs());
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..31 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    assert_eq!(*fires.lock().unwrap(), vec![1, 3, 7, 15, 23, 31]);
+    assert_eq!(timer.get_interval().await, Duration::from_secs(8));
+    timer.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn decaying_timer_rejects_invalid_parameters() {
+    let timer = Timer::new();
+    let callback = || async { Ok(()) };
+    for (initial, factor, ceiling) in [
+        (Duration::from_secs(1), 0.5, Duration::from_secs(8)),
+        (Duration::from_secs(1), f64::NAN, Duration::from_secs(8)),
+        (Duration::from_secs(9), 2.0, Duration::from_secs(8)),
+        (Duration::ZERO, 2.0, Duration::from_secs(8)),
+    ] {
+        assert!(timer
+            .start_decaying(initial, factor, ceiling, callback)
+            .await
+            .unwrap_err()
+            .is_invalid_parameter());
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn pending_resume_reports_an_unobserved_resume() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    assert!(!timer.pending_resume().await);
+
+    timer.pause().await.unwrap();
+    settle().await;
+    assert!(!timer.pending_resume().await);
+
+    // On a current-thread runtime the run task cannot observe the resume
+    // until this task yields, which holds the race window open.
+    timer.resume().await.unwrap();
+    assert_eq!(timer.get_state().await, TimerState::Running);
+    assert!(timer.pending_resume().await);
+
+    settle().await;
+    assert!(!timer.pending_resume().await);
+    timer.stop().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn stats_since_reports_the_executions_within_the_window() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)),
+            CountingCallback {
+                executions: Arc::clone(&executions),
+                fail: false,
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    let checkpoint = timer.checkpoint().await;
+    for _ in 0..4 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    timer.stop().await.unwrap();
+
+    let delta = timer.stats_since(&checkpoint).await;
+    assert_eq!(executions.load(Ordering::SeqCst), 7);
+    assert_eq!(delta.execution_count, 4);
+    assert_eq!(delta.successful_executions, 4);
+    assert_eq!(delta.failed_executions, 0);
+    assert_eq!(delta.elapsed, Duration::from_secs(4));
+    assert_eq!(timer.get_statistics().await.execution_count, 7);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn stats_since_follows_taken_and_preserved_statistics() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    for _ in 0..3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    // Taking the statistics restarts the window at the reset.
+    let checkpoint = timer.checkpoint().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    assert_eq!(timer.take_statistics().await.execution_count, 4);
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    assert_eq!(timer.stats_since(&checkpoint).await.execution_count, 2);
+
+    // A restart that preserves the statistics keeps measuring from the checkpoint.
+    let checkpoint = timer.checkpoint().await;
+    timer.restart_preserving_stats().await.unwrap();
+    settle().await;
+    for _ in 0..3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    assert_eq!(timer.get_statistics().await.execution_count, 5);
+    assert_eq!(timer.stats_since(&checkpoint).await.execution_count, 3);
+    timer.stop().await.unwrap();
+}
+
+#[cfg(feature = "profiling")]
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn lock_profile_records_hold_time_after_ticks() {
+    let timer = Timer::new();
+    assert_eq!(timer.lock_profile(), LockProfile::default());
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(5),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..5 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    timer.join().await.unwrap();
+
+    let profile = timer.lock_profile();
+    assert!(profile.statistics > Duration::ZERO);
+    assert!(profile.next_fire > Duration::ZERO);
+    assert!(profile.total() >= profile.statistics + profile.next_fire);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn readiness_probe_completes_on_the_first_ready_probe() {
+    let probes = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&probes);
+    let timer = Timer::new();
+    timer
+        .start_readiness_probe(Duration::from_secs(1), Duration::from_secs(10), move || {
+            let counter = Arc::clone(&counter);
+            async move { Ok(counter.fetch_add(1, Ordering::SeqCst) + 1 >= 3) }
+        })
+        .await
+        .unwrap();
+    settle().await;
+    assert_eq!(probes.load(Ordering::SeqCst), 1);
+
+    for _ in 0..5 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert_eq!(outcome.statistics.successful_executions, 3);
+    assert_eq!(outcome.statistics.skipped_executions, 0);
+    assert_eq!(probes.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn readiness_probe_stops_when_the_deadline_passes() {
+    let timer = Timer::new();
+    timer
+        .start_readiness_probe(
+            Duration::from_secs(1),
+            Duration::from_millis(2_500),
+            || async { Ok(false) },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    for _ in 0..5 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Stopped);
+    assert_eq!(outcome.statistics.execution_count, 3);
+    assert_eq!(outcome.statistics.successful_executions, 3);
+    assert_eq!(outcome.statistics.skipped_executions, 0);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn time_until_next_counts_down_to_the_next_tick() {
+    let timer = Timer::new();
+    assert_eq!(timer.time_until_next().await, None);
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    assert_eq!(timer.time_until_next().await, Some(Duration::from_secs(1)));
+
+    advance(Duration::from_millis(300)).await;
+    settle().await;
+    assert_eq!(
+        timer.time_until_next().await,
+        Some(Duration::from_millis(700))
+    );
+
+    advance(Duration::from_millis(700)).await;
+    settle().await;
+    assert_eq!(timer.time_until_next().await, Some(Duration::from_secs(1)));
+
+    timer.pause().await.unwrap();
+    settle().await;
+    assert_eq!(timer.time_until_next().await, None);
+
+    timer.resume().await.unwrap();
+    settle().await;
+    assert!(timer.time_until_next().await.is_some());
+
+    let _ = timer.stop().await.unwrap();
+    assert_eq!(timer.time_until_next().await, None);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn callbacks_accumulate_custom_metrics_across_ticks() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)),
+            ContextCallback::new(|context: TimerContext| async move {
+                context.record_metric("processed", 10.0);
+                if context.execution() % 2 == 0 {
+                    context.record_metric("flushed", 1.0);
+                }
+                Ok(())
+            }),
+        )
+        .await
+        .unwrap();
+    settle().await;
+    assert!(timer.custom_totals().is_empty());
+
+    for tick in 1..=3 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        assert_eq!(
+            timer.custom_totals().get("processed"),
+            Some(&(10.0 * f64::from(tick)))
+        );
+    }
+    assert_eq!(timer.custom_totals().get("flushed"), Some(&1.0));
+
+    let _ = timer.stop().await.unwrap();
+    timer
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    assert!(timer.custom_totals().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn restart_reruns_the_last_callback_and_configuration() {
+    let timer = Timer::new();
+    assert!(timer.restart().await.err().unwrap().is_invalid_parameter());
+
+    let executions = Arc::new(AtomicUsize::new(0));
+    timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2),
+            CountingCallback {
+                executions: Arc::clone(&executions),
+                fail: false,
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+
+    let run_id = timer.restart().await.unwrap();
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Running);
+    assert_eq!(timer.get_statistics().await.execution_count, 0);
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.run_id, run_id);
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 2);
+    assert_eq!(executions.load(Ordering::SeqCst), 4);
+
+    timer.restart_preserving_stats().await.unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    let _ = timer.stop().await.unwrap();
+    assert_eq!(timer.get_statistics().await.execution_count, 3);
+    assert_eq!(executions.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn resume_waits_only_for_the_rest_of_the_interrupted_interval() {
+    for schedule in [
+        RecurringSchedule::new(Duration::from_secs(3)),
+        RecurringSchedule::new(Duration::from_secs(3)).fixed_rate(),
+    ] {
+        let executions = Arc::new(AtomicUsize::new(0));
+        let timer = Timer::new();
+        timer
+            .start_recurring(
+                schedule,
+                CountingCallback {
+                    executions: Arc::clone(&executions),
+                    fail: false,
+                },
+            )
+            .await
+            .unwrap();
+        settle().await;
+
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+        timer.pause().await.unwrap();
+        settle().await;
+        advance(Duration::from_secs(5)).await;
+        settle().await;
+        assert_eq!(executions.load(Ordering::SeqCst), 0);
+
+        timer.resume().await.unwrap();
+        settle().await;
+        advance(Duration::from_millis(1_900)).await;
+        settle().await;
+        assert_eq!(executions.load(Ordering::SeqCst), 0);
+        advance(Duration::from_millis(100)).await;
+        settle().await;
+        assert_eq!(executions.load(Ordering::SeqCst), 1);
+
+        advance(Duration::from_secs(3)).await;
+        settle().await;
+        assert_eq!(executions.load(Ordering::SeqCst), 2);
+
+        let _ = timer.cancel().await.unwrap();
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn natural_completion_and_manual_stop_leave_different_states() {
+    let completed = Timer::new();
+    completed
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+    completed.wait_for_state(TimerState::Completed).await;
+    assert_eq!(completed.try_state(), Some(TimerState::Completed));
+    assert!(!TimerState::Completed.is_active());
+
+    let stopped = Timer::new();
+    stopped
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    assert!(stopped.get_state().await.is_active());
+    let _ = stopped.stop().await.unwrap();
+    assert_eq!(stopped.get_state().await, TimerState::Stopped);
+    assert!(!TimerState::Stopped.is_active());
+
+    completed
+        .start_once(Duration::from_secs(1), || async { Ok(()) })
+        .await
+        .unwrap();
+    let _ = completed.cancel().await.unwrap();
+    assert_eq!(completed.get_state().await, TimerState::Stopped);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn pause_for_resumes_automatically_unless_overridden() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    timer.pause_for(Duration::from_secs(3)).await.unwrap();
+    assert_eq!(timer.get_state().await, TimerState::Paused);
+    advance(Duration::from_millis(2_900)).await;
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Paused);
+    advance(Duration::from_millis(100)).await;
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Running);
+
+    // A manual resume and pause in between cancels the automatic resume.
+    timer.pause_for(Duration::from_secs(3)).await.unwrap();
+    timer.resume().await.unwrap();
+    timer.pause().await.unwrap();
+    advance(Duration::from_secs(5)).await;
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Paused);
+
+    timer.resume().await.unwrap();
+    timer.pause_for(Duration::from_secs(3)).await.unwrap();
+    let _ = timer.stop().await.unwrap();
+    advance(Duration::from_secs(5)).await;
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Stopped);
+    assert!(timer
+        .pause_for(Duration::from_secs(1))
+        .await
+        .unwrap_err()
+        .is_not_running());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn since_last_execution_grows_until_the_next_tick() {
+    let timer = Timer::new();
+    assert_eq!(timer.since_last_execution().await, None);
+
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(10)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+    assert_eq!(timer.since_last_execution().await, None);
+
+    advance(Duration::from_secs(10)).await;
+    settle().await;
+    assert_eq!(timer.since_last_execution().await, Some(Duration::ZERO));
+
+    advance(Duration::from_secs(3)).await;
+    let first = timer.since_last_execution().await.unwrap();
+    advance(Duration::from_secs(4)).await;
+    let second = timer.since_last_execution().await.unwrap();
+    assert_eq!(first, Duration::from_secs(3));
+    assert_eq!(second, Duration::from_secs(7));
+
+    advance(Duration::from_secs(3)).await;
+    settle().await;
+    assert_eq!(timer.since_last_execution().await, Some(Duration::ZERO));
+
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn pause_at_pauses_the_timer_at_the_requested_time() {
+    let timer = Timer::new();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    timer
+        .pause_at(SystemTime::now() + Duration::from_millis(200))
+        .await
+        .unwrap();
+    advance(Duration::from_millis(150)).await;
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Running);
+
+    advance(Duration::from_millis(50)).await;
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Paused);
+
+    let _ = timer.cancel().await.unwrap();
+    assert!(timer
+        .pause_at(SystemTime::now())
+        .await
+        .unwrap_err()
+        .is_not_running());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn scheduled_pause_and_resume_tasks_exit_with_the_run() {
+    let metrics = tokio::runtime::Handle::current().metrics();
+    let stopped = Timer::new();
+    stopped
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+    let completed = Timer::new();
+    completed
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap();
+    settle().await;
+    assert_eq!(metrics.num_alive_tasks(), 2);
+
+    let in_an_hour = SystemTime::now() + Duration::from_secs(3_600);
+    stopped.pause_for(Duration::from_secs(3_600)).await.unwrap();
+    stopped.pause_at(in_an_hour).await.unwrap();
+    completed.pause_at(in_an_hour).await.unwrap();
+    assert_eq!(metrics.num_alive_tasks(), 5);
+
+    stopped.stop().await.unwrap();
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+    assert_eq!(
+        completed.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+    settle().await;
+    assert_eq!(metrics.num_alive_tasks(), 0);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn start_at_system_time_fires_at_the_requested_wall_clock_time() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let timer = Timer::new();
+    timer
+        .start_at_system_time(
+            SystemTime::now() + Duration::from_millis(200),
+            CountingCallback {
+                executions: Arc::clone(&executions),
+                fail: false,
+            },
+        )
+        .await
+        .unwrap();
+    settle().await;
+
+    advance(Duration::from_millis(190)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 0);
+
+    advance(Duration::from_millis(20)).await;
+    settle().await;
+    assert_eq!(executions.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        timer.join().await.unwrap().reason,
+        TimerFinishReason::Completed
+    );
+
+    assert!(timer
+        .start_at_system_time(SystemTime::now() - Duration::from_secs(1), || async {
+            Ok(())
+        })
+        .await
+        .unwrap_err()
+        .is_invalid_parameter());
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn created_at_precedes_the_first_execution() {
+    let timer = Timer::new();
+    let created_at = timer.created_at();
+    assert!(timer.created_at_system_time() <= SystemTime::now());
+
+    advance(Duration::from_secs(5)).await;
+    let executed_at = Arc::new(StdMutex::new(None));
+    let executed_at_for_callback = Arc::clone(&executed_at);
+    timer
+        .start_once(Duration::from_secs(1), move || {
+            let executed_at = Arc::clone(&executed_at_for_callback);
+            async move {
+                *executed_at.lock().unwrap() = Some(Instant::now());
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+    advance(Duration::from_secs(1)).await;
+    settle().await;
+
+    let executed_at = executed_at.lock().unwrap().unwrap();
+    assert_eq!(
+        executed_at.duration_since(created_at),
+        Duration::from_secs(6)
+    );
+    assert_eq!(timer.snapshot().await.created_at, created_at);
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn expiration_count_boundaries_are_enforced() {
+    let timer = Timer::new();
+    let error = timer
+        .start_recurring(
+            RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(0),
+            || async { Ok(()) },
+        )
+        .await
+        .unwrap_err();
+    assert!(error.is_invalid_parameter());
+    assert_eq!(timer.get_state().await, TimerState::Stopped);
+
+    for expiration_count in [1, 3] {
+        let executions = Arc::new(AtomicUsize::new(0));
+        let timer = Timer::new();
+        timer
+            .start_recurring(
+                RecurringSchedule::new(Duration::from_secs(1))
+                    .with_expiration_count(expiration_count),
+                CountingCallback {
+                    executions: Arc::clone(&executions),
+                    fail: false,
+                },
+            )
+            .await
+            .unwrap();
+        settle().await;
+
+        for _ in 0..expiration_count + 2 {
+            advance(Duration::from_secs(1)).await;
+            settle().await;
+        }
+
+        let outcome = timer.join().await.unwrap();
+        assert_eq!(outcome.reason, TimerFinishReason::Completed);
+        assert_eq!(outcome.statistics.execution_count, expiration_count);
+        assert_eq!(executions.load(Ordering::SeqCst), expiration_count);
+    }
+}
+
+#[tokio::test]
+async fn builder_build_reports_the_first_configuration_problem() {
+    let cases = [
+        (
+            Timer::once(Duration::ZERO),
+            "Interval must be greater than zero.",
+        ),
+        (
+            Timer::recurring(
+                RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(0),
+            ),
+            "Expiration count must be greater than zero.",
+        ),
+        (
+            Timer::recurring(
+                RecurringSchedule::new(Duration::from_secs(1)).with_jitter(Duration::ZERO),
+            ),
+            "Jitter must be greater than zero.",
+        ),
+        (
+            Timer::once(Duration::from_secs(1)).callback_timeout(Duration::ZERO),
+            "Callback timeout must be greater than zero.",
+        ),
+        (
+            Timer::once(Duration::from_secs(1))
+                .callback_timeout(Duration::from_secs(1))
+                .soft_timeout(Duration::from_secs(2)),
+            "Soft timeout must be shorter than the callback timeout.",
+        ),
+        (
+            Timer::once(Duration::from_secs(1)).event_capacity(0),
+            "Event capacity must be greater than zero.",
+        ),
+    ];
+
+    for (builder, message) in cases {
+        assert_eq!(
+            builder.build().await.err(),
+            Some(TimerError::invalid_parameter(message))
+        );
+    }
+}
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn builder_build_returns_a_stopped_timer_that_restart_with_starts() {
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(1)).with_expiration_count(2))
+            .label("built")
+            .build()
+            .await
+            .unwrap();
+    assert_eq!(timer.get_state().await, TimerState::Stopped);
+    assert_eq!(timer.get_interval().await, Duration::from_secs(1));
+    assert_eq!(timer.label().await.as_deref(), Some("built"));
+
+    timer.restart_with(|| async { Ok(()) }).await.unwrap();
+    settle().await;
+    for _ in 0..2 {
+        advance(Duration::from_secs(1)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 2);
+}
+
+#[cfg(feature = "profiling")]
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn statistics_lock_contention_counts_waiting_acquisitions() {
+    let timer = Timer::new();
+    let _ = timer.get_statistics().await;
+    assert_eq!(timer.statistics_lock_contention(), 0);
+
+    let guard = timer.inner.statistics.lock().await;
+    let reader = {
+        let timer = timer.clone();
+        tokio::spawn(async move { timer.get_statistics().await })
+    };
+    settle().await;
+    assert_eq!(timer.statistics_lock_contention(), 1);
+    assert!(!reader.is_finished());
+
+    drop(guard);
+    assert_eq!(reader.await.unwrap(), TimerStatistics::default());
+    assert_eq!(timer.statistics_lock_contention(), 1);
+}
@@ -1,6 +1,9 @@
 use std::time::Duration;
 
-use timer_lib::{RecurringSchedule, Timer, TimerEvent, TimerFinishReason, TimerRegistry};
+use timer_lib::{
+    RecurringSchedule, Timer, TimerBuilder, TimerCompletion, TimerContext, TimerEvent, TimerEvents,
+    TimerFinishReason, TimerRegistry, WeakTimer,
+};
 use tokio::task::yield_now;
 use tokio::time::{advance, Instant};
 
@@ -10,6 +13,19 @@ async fn settle() {
     }
 }
 
+#[test]
+fn public_handles_are_send_and_sync_without_unsafe_impls() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Timer>();
+    assert_send_sync::<WeakTimer>();
+    assert_send_sync::<TimerBuilder>();
+    assert_send_sync::<TimerRegistry>();
+    assert_send_sync::<TimerContext>();
+    assert_send_sync::<TimerEvents>();
+    assert_send_sync::<TimerCompletion>();
+}
+
 #[tokio::test(flavor = "current_thread", start_paused = true)]
 async fn timer_closure_api_is_simple_to_use() {
     let timer = Timer::new();
@@ -323,7 +339,7 @@ async fn labels_and_registry_snapshots_are_available_from_the_public_api() {
     );
 
     let registry = TimerRegistry::new();
-    let timer_id = registry.insert(timer.clone()).await;
+    let timer_id = registry.insert(timer.clone()).await.unwrap();
     let listed = registry.list().await;
     assert!(listed.iter().any(|entry| {
         entry.id == timer_id && entry.metadata.label.as_deref() == Some("billing")
@@ -365,6 +381,34 @@ async fn jitter_and_retry_backoff_are_available_from_the_public_api() {
     assert_eq!(outcome.statistics.successful_executions, 1);
 }
 
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn builder_configures_every_option_before_the_timer_starts() {
+    let timer =
+        Timer::recurring(RecurringSchedule::new(Duration::from_secs(2)).with_expiration_count(2))
+            .label("heartbeat")
+            .callback_timeout(Duration::from_secs(1))
+            .retry_policy(timer_lib::RetryPolicy::new(1))
+            .build()
+            .await
+            .unwrap();
+
+    assert_eq!(timer.get_state().await, timer_lib::TimerState::Stopped);
+    assert_eq!(timer.get_interval().await, Duration::from_secs(2));
+    assert_eq!(timer.get_expiration_count().await, Some(2));
+    assert_eq!(timer.label().await.as_deref(), Some("heartbeat"));
+
+    timer.restart_with(|| async { Ok(()) }).await.unwrap();
+    settle().await;
+    for _ in 0..2 {
+        advance(Duration::from_secs(2)).await;
+        settle().await;
+    }
+
+    let outcome = timer.join().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Completed);
+    assert_eq!(outcome.statistics.execution_count, 2);
+}
+
 #[cfg(feature = "test-util")]
 #[tokio::test(flavor = "current_thread")]
 async fn mock_runtime_is_available_from_the_public_api() {
@@ -381,3 +425,59 @@ async fn mock_runtime_is_available_from_the_public_api() {
         TimerFinishReason::Completed
     );
 }
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn test_scheduler_fires_every_elapsed_interval() {
+    let scheduler = timer_lib::TestScheduler::new(Duration::from_secs(1));
+    let timer = scheduler.timer();
+    timer
+        .start_recurring(RecurringSchedule::new(Duration::from_secs(1)), || async {
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    scheduler.advance_time(Duration::from_secs(5)).await;
+    assert_eq!(timer.get_statistics().await.execution_count, 5);
+
+    scheduler.advance(3).await;
+    assert_eq!(timer.get_statistics().await.execution_count, 8);
+    let _ = timer.cancel().await.unwrap();
+}
+
+#[cfg(feature = "test-util")]
+#[tokio::test(flavor = "current_thread")]
+async fn test_scheduler_runs_registry_callbacks_in_deadline_order() {
+    use std::sync::{Arc, Mutex};
+
+    let scheduler = timer_lib::TestScheduler::new(Duration::from_secs(1));
+    let registry = scheduler.registry();
+    let fired = Arc::new(Mutex::new(Vec::new()));
+    let mut timers = Vec::new();
+    for (name, seconds) in [("fast", 2), ("slow", 3)] {
+        let fired = Arc::clone(&fired);
+        let (_, timer) = registry
+            .start_recurring(
+                RecurringSchedule::new(Duration::from_secs(seconds)),
+                move || {
+                    let fired = Arc::clone(&fired);
+                    async move {
+                        fired.lock().unwrap().push(name);
+                        Ok(())
+                    }
+                },
+            )
+            .await
+            .unwrap();
+        timers.push(timer);
+    }
+
+    scheduler.advance(5).await;
+    assert_eq!(*fired.lock().unwrap(), vec!["fast", "slow", "fast"]);
+
+    scheduler.advance(7).await;
+    assert_eq!(timers[0].get_statistics().await.execution_count, 6);
+    assert_eq!(timers[1].get_statistics().await.execution_count, 4);
+    registry.cancel_all().await;
+}
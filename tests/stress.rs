@@ -4,7 +4,7 @@ use std::sync::{
 };
 use std::time::Duration;
 
-use timer_lib::{RecurringSchedule, Timer, TimerFinishReason, TimerRegistry};
+use timer_lib::{RecurringSchedule, Timer, TimerFinishReason, TimerRegistry, TimerState};
 use tokio::task::yield_now;
 use tokio::time::{advance, timeout};
 
@@ -114,3 +114,92 @@ async fn registry_cancel_all_stops_many_recurring_timers_concurrently() {
 
     assert!(registry.active_ids().await.is_empty());
 }
+
+#[tokio::test(flavor = "current_thread", start_paused = true)]
+async fn rapid_pause_resume_toggling_never_leaves_the_timer_stuck() {
+    let executions = Arc::new(AtomicUsize::new(0));
+    let executions_for_callback = Arc::clone(&executions);
+    let timer = Timer::recurring(RecurringSchedule::new(Duration::from_millis(5)))
+        .start(move || {
+            let executions = Arc::clone(&executions_for_callback);
+            async move {
+                executions.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+        .await
+        .unwrap();
+    settle().await;
+
+    // Interleave pauses and resumes with the run task observing them.
+    let togglers: Vec<_> = (0..4)
+        .map(|_| {
+            let timer = timer.clone();
+            tokio::spawn(async move {
+                for _ in 0..250 {
+                    let _ = timer.pause().await;
+                    yield_now().await;
+                    let _ = timer.resume().await;
+                }
+            })
+        })
+        .collect();
+    for toggler in togglers {
+        toggler.await.unwrap();
+    }
+    settle().await;
+    assert_eq!(timer.get_state().await, TimerState::Running);
+
+    let before = executions.load(Ordering::SeqCst);
+    for _ in 0..3 {
+        advance(Duration::from_millis(5)).await;
+        settle().await;
+    }
+    assert_eq!(
+        executions.load(Ordering::SeqCst),
+        before + 3,
+        "timer stayed paused after resume"
+    );
+
+    let outcome = timer.cancel().await.unwrap();
+    assert_eq!(outcome.reason, TimerFinishReason::Cancelled);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_inserts_and_removals_assign_unique_gapless_ids() {
+    const TASKS: usize = 8;
+    const INSERTS_PER_TASK: usize = 64;
+
+    let registry = TimerRegistry::new();
+    let workers: Vec<_> = (0..TASKS)
+        .map(|worker| {
+            let registry = registry.clone();
+            tokio::spawn(async move {
+                let mut ids = Vec::with_capacity(INSERTS_PER_TASK);
+                for index in 0..INSERTS_PER_TASK {
+                    let id = registry.insert(Timer::new()).await.unwrap();
+                    if (worker + index) % 3 == 0 {
+                        registry.remove(id).await;
+                    }
+                    yield_now().await;
+                    ids.push(id);
+                }
+                ids
+            })
+        })
+        .collect();
+
+    let mut all_ids = Vec::with_capacity(TASKS * INSERTS_PER_TASK);
+    for worker in workers {
+        let ids = timeout(Duration::from_secs(5), worker)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        all_ids.extend(ids);
+    }
+
+    all_ids.sort_unstable();
+    let expected: Vec<u64> = (0..(TASKS * INSERTS_PER_TASK) as u64).collect();
+    assert_eq!(all_ids, expected);
+}